@@ -0,0 +1,12 @@
+#![no_main]
+// Décodage d'un message du serveur à partir d'octets quelconques, comme le fait `GameClient` pour chaque trame reçue.
+use libfuzzer_sys::fuzz_target;
+use number_game::codec::{decode, write_message};
+use number_game::protocol::ServerMessage;
+
+fuzz_target!(|payload: &[u8]| {
+    if let Ok(message) = decode::<ServerMessage>(payload) {
+        // Un message décodé doit pouvoir être réencodé.
+        write_message(&mut Vec::new(), &message).unwrap();
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+// Lecture de trames successives dans un flux d'octets quelconques, puis décodage de leur contenu.
+use libfuzzer_sys::fuzz_target;
+use number_game::codec::{decode, read_frame, MAX_FRAME_LEN};
+use number_game::protocol::ClientMessage;
+
+fuzz_target!(|stream: &[u8]| {
+    let mut reader = stream;
+    while let Ok(Some(payload)) = read_frame(&mut reader) {
+        assert!(payload.len() <= MAX_FRAME_LEN as usize);
+        let _ = decode::<ClientMessage>(&payload);
+    }
+});
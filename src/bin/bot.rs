@@ -0,0 +1,101 @@
+use std::env;
+use std::io;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+use crossbeam::channel::RecvTimeoutError;
+use number_game::bot::{Action, Bot, Strategy};
+use number_game::game_client::GameClient;
+use number_game::i18n::parse_difficulty;
+use number_game::protocol::{Difficulty, Language};
+
+const USAGE: &str = "Usage: bot [--address <host:port>] [--count <n>] [--strategy <binary|random|human|bad>] [--name <name>] [--vote <easy|medium|hard>]";
+
+// Options de la ligne de commande.
+struct Options {
+    address: String, // Adresse du serveur.
+    count: u32, // Nombre de bots à lancer.
+    strategy: Strategy, // Stratégie de jeu des bots.
+    name: String, // Nom des bots (suivi d'un numéro s'il y en a plusieurs).
+    vote: Option<Difficulty>, // Difficulté pour laquelle les bots votent (au hasard par défaut).
+}
+
+// Lit les options de la ligne de commande.
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        address: "127.0.0.1:7878".to_string(),
+        count: 1,
+        strategy: Strategy::BinarySearch,
+        name: "Bot".to_string(),
+        vote: None,
+    };
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("missing value for {}", flag))?;
+        match flag.as_str() {
+            "--address" => options.address = value,
+            "--count" => options.count = value.parse().map_err(|_| format!("invalid count: {}", value))?,
+            "--strategy" => options.strategy = value.parse()?,
+            "--name" => options.name = value,
+            "--vote" => options.vote = Some(parse_difficulty(&value).ok_or_else(|| format!("unknown difficulty: {}", value))?),
+            _ => return Err(format!("unknown option: {}", flag)),
+        }
+    }
+    Ok(options)
+}
+
+// Fait jouer un bot jusqu'à ce que le serveur ferme la connexion.
+// Chaque action est envoyée après le temps de réflexion du bot.
+fn run_bot(address: &str, mut bot: Bot) -> io::Result<()> {
+    let client = GameClient::connect(address)?;
+    client.join(bot.name(), Language::English)?;
+    let mut scheduled: Option<(Instant, Action)> = None; // Prochaine action et instant de son envoi.
+    loop {
+        let timeout = scheduled.map_or(Duration::from_secs(1), |(at, _)| at.saturating_duration_since(Instant::now()));
+        match client.events().recv_timeout(timeout) {
+            Ok(message) => {
+                if let Some(action) = bot.handle(&message) {
+                    scheduled = Some((Instant::now() + bot.think_time(), action));
+                }
+            },
+            Err(RecvTimeoutError::Timeout) => {},
+            Err(RecvTimeoutError::Disconnected) => return Ok(()), // Le serveur a fermé la connexion.
+        }
+        if let Some((at, action)) = scheduled {
+            if Instant::now() >= at {
+                scheduled = None;
+                println!("{}: {:?}", bot.name(), action);
+                match action {
+                    Action::Vote(difficulty) => client.vote(difficulty)?,
+                    Action::Guess(guess) => client.guess(guess)?,
+                    Action::Ready => client.ready()?,
+                }
+            }
+        }
+    }
+}
+
+fn main() {
+    let options = parse_options(env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{}\n{}", error, USAGE);
+        process::exit(2);
+    });
+
+    // Un thread par bot, chacun avec sa propre connexion au serveur.
+    let handles: Vec<_> = (1..=options.count).map(|index| {
+        let name = if options.count == 1 { options.name.clone() } else { format!("{}{}", options.name, index) };
+        let mut bot = Bot::new(name.clone(), options.strategy, rand::random());
+        if let Some(difficulty) = options.vote {
+            bot.set_vote(difficulty);
+        }
+        let address = options.address.clone();
+        thread::spawn(move || {
+            if let Err(error) = run_bot(&address, bot) {
+                eprintln!("{}: {}", name, error);
+            }
+        })
+    }).collect();
+
+    for handle in handles {
+        handle.join().ok();
+    }
+}
@@ -0,0 +1,79 @@
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use number_game::tls::generate_certificates;
+
+const USAGE: &str = "Usage: gencert [--dir <directory>] [--name <host|ip>]...";
+
+// Options de la ligne de commande.
+struct Options {
+    dir: PathBuf, // Dossier où les certificats sont écrits.
+    names: Vec<String>, // Noms d'hôte et adresses IP pour lesquels le certificat du serveur est valable.
+}
+
+// Lit les options de la ligne de commande.
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options { dir: PathBuf::from("certs"), names: vec![] };
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("missing value for {}", flag))?;
+        match flag.as_str() {
+            "--dir" => options.dir = PathBuf::from(value),
+            "--name" => options.names.push(value),
+            _ => return Err(format!("unknown option: {}", flag)),
+        }
+    }
+    if options.names.is_empty() {
+        options.names = vec!["localhost".to_string(), "127.0.0.1".to_string()]; // Le client se connecte à 127.0.0.1
+    }
+    Ok(options)
+}
+
+// Écrit un fichier PEM dans le dossier des certificats et renvoie son chemin.
+// Un fichier privé (la clé du serveur) n'est lisible que par son propriétaire sous Unix.
+fn write(dir: &Path, name: &str, contents: &str, #[cfg_attr(not(unix), allow(unused_variables))] private: bool) -> io::Result<PathBuf> {
+    let path = dir.join(name);
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&path)?;
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?; // Le fichier existait peut-être déjà, avec d'autres droits
+    }
+    file.write_all(contents.as_bytes())?;
+    Ok(path)
+}
+
+fn main() {
+    let options = parse_options(env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{}\n{}", error, USAGE);
+        process::exit(2);
+    });
+
+    // Autorité de certification auto-signée et certificat du serveur signé par elle, pour tester TLS en local.
+    let certificates = generate_certificates(&options.names).unwrap_or_else(|error| {
+        eprintln!("Could not generate the certificates: {}", error);
+        process::exit(1);
+    });
+    let written = fs::create_dir_all(&options.dir).and_then(|_| {
+        Ok((
+            write(&options.dir, "ca.pem", &certificates.ca, false)?,
+            write(&options.dir, "cert.pem", &certificates.cert, false)?,
+            write(&options.dir, "key.pem", &certificates.key, true)?,
+        ))
+    });
+    let (ca, cert, key) = written.unwrap_or_else(|error| {
+        eprintln!("Could not write the certificates to {}: {}", options.dir.display(), error);
+        process::exit(1);
+    });
+    println!("Certificates for {} written to {}", options.names.join(", "), options.dir.display());
+    println!("Server: TLS_CERT={} TLS_KEY={} cargo run --bin server", cert.display(), key.display());
+    println!("Client: cargo run --bin client -- --ca {}", ca.display());
+}
@@ -0,0 +1,207 @@
+use std::collections::VecDeque;
+use std::env;
+use std::process;
+use std::time::{Duration, Instant};
+use crossbeam::channel::RecvTimeoutError;
+use crossbeam::thread::scope;
+use number_game::bot::{Action, Bot, Strategy};
+use number_game::game_client::GameClient;
+use number_game::protocol::{Language, ServerMessage};
+
+const USAGE: &str = "Usage: loadtest [--address <host:port>] [--clients <n>] [--duration <seconds>] [--strategy <binary|random|human|bad>]";
+const GRACE_PERIOD: Duration = Duration::from_secs(2); // Temps laissé au serveur pour répondre aux dernières devinettes.
+
+// Options de la ligne de commande.
+struct Options {
+    address: String, // Adresse du serveur à tester.
+    clients: u32, // Nombre de clients simulés.
+    duration: Duration, // Durée du test.
+    strategy: Strategy, // Stratégie de jeu des clients simulés.
+}
+
+// Lit les options de la ligne de commande.
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        address: "127.0.0.1:7878".to_string(),
+        clients: 20,
+        duration: Duration::from_secs(30),
+        strategy: Strategy::BinarySearch,
+    };
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("missing value for {}", flag))?;
+        match flag.as_str() {
+            "--address" => options.address = value,
+            "--clients" => options.clients = value.parse().map_err(|_| format!("invalid number of clients: {}", value))?,
+            "--duration" => options.duration = Duration::from_secs(value.parse().map_err(|_| format!("invalid duration: {}", value))?),
+            "--strategy" => options.strategy = value.parse()?,
+            _ => return Err(format!("unknown option: {}", flag)),
+        }
+    }
+    Ok(options)
+}
+
+// Statistiques d'un client simulé (et, une fois additionnées, de l'ensemble du test).
+#[derive(Default)]
+struct Stats {
+    sent: u64, // Messages envoyés au serveur.
+    received: u64, // Messages reçus du serveur.
+    guesses: u64, // Devinettes envoyées.
+    rounds: u64, // Manches commencées, vues par ce client.
+    wins: u64, // Manches gagnées.
+    latencies: Vec<Duration>, // Temps entre l'envoi d'une devinette et la réception de sa réponse (indice ou refus).
+    unanswered: u64, // Devinettes restées sans réponse à la fin du test.
+    errors: Vec<String>, // Erreurs de connexion, d'écriture ou déconnexions inattendues.
+}
+
+impl Stats {
+    // Ajoute les statistiques d'un client au total.
+    fn merge(&mut self, other: Stats) {
+        self.sent += other.sent;
+        self.received += other.received;
+        self.guesses += other.guesses;
+        self.rounds += other.rounds;
+        self.wins += other.wins;
+        self.latencies.extend(other.latencies);
+        self.unanswered += other.unanswered;
+        self.errors.extend(other.errors);
+    }
+}
+
+// Renvoie le centile donné (entre 0 et 100) d'une liste de durées triée.
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+// Fait jouer un client simulé pendant la durée du test, sans temps de réflexion, en mesurant les temps de réponse.
+fn run_client(name: String, options: &Options, deadline: Instant) -> Stats {
+    let mut stats = Stats::default();
+    let client = match GameClient::connect(options.address.as_str()) {
+        Ok(client) => client,
+        Err(error) => {
+            stats.errors.push(format!("{}: connection failed: {}", name, error));
+            return stats;
+        },
+    };
+    let mut bot = Bot::new(name.clone(), options.strategy, rand::random());
+    let mut pending: VecDeque<Instant> = VecDeque::new(); // Instants d'envoi des devinettes en attente de réponse.
+    let mut action = None;
+    let send = |result: std::io::Result<()>, stats: &mut Stats| match result {
+        Ok(()) => stats.sent += 1,
+        Err(error) => stats.errors.push(format!("{}: write failed: {}", name, error)),
+    };
+    send(client.join(&name, Language::English), &mut stats);
+
+    loop {
+        if let Some(action) = action.take() {
+            match action {
+                Action::Vote(difficulty) => send(client.vote(difficulty), &mut stats),
+                Action::Ready => send(client.ready(), &mut stats),
+                Action::Guess(guess) => {
+                    pending.push_back(Instant::now());
+                    stats.guesses += 1;
+                    send(client.guess(guess), &mut stats);
+                },
+            }
+        }
+        // Après la fin du test, le client attend seulement les réponses aux devinettes déjà envoyées.
+        let now = Instant::now();
+        if now >= deadline && (pending.is_empty() || now >= deadline + GRACE_PERIOD) {
+            break;
+        }
+        let timeout = if now < deadline { deadline - now } else { deadline + GRACE_PERIOD - now };
+        let message = match client.events().recv_timeout(timeout) {
+            Ok(message) => message,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => {
+                stats.errors.push(format!("{}: disconnected by the server", bot.name()));
+                break;
+            },
+        };
+        stats.received += 1;
+        match &message {
+            ServerMessage::Hint(_) | ServerMessage::GuessRejected(_) => {
+                if let Some(sent) = pending.pop_front() {
+                    stats.latencies.push(sent.elapsed());
+                }
+            },
+            ServerMessage::GameStart(_) => stats.rounds += 1,
+            ServerMessage::PlayerWon(winner) if winner == bot.name() => stats.wins += 1,
+            _ => {},
+        }
+        if Instant::now() < deadline {
+            action = bot.handle(&message);
+        }
+    }
+    stats.unanswered = pending.len() as u64;
+    client.close();
+    stats
+}
+
+fn main() {
+    let options = parse_options(env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{}\n{}", error, USAGE);
+        process::exit(2);
+    });
+    println!("Load test: {} clients for {}s against {}", options.clients, options.duration.as_secs(), options.address);
+
+    let start = Instant::now();
+    let deadline = start + options.duration;
+    let mut total = Stats::default();
+    scope(|scope| {
+        // Un thread par client simulé, comme le serveur le fait pour chaque connexion.
+        let handles: Vec<_> = (1..=options.clients)
+            .map(|index| {
+                let options = &options;
+                scope.spawn(move |_| run_client(format!("Load{}", index), options, deadline))
+            })
+            .collect();
+        for handle in handles {
+            match handle.join() {
+                Ok(stats) => total.merge(stats),
+                Err(_) => total.errors.push("client thread panicked".to_string()),
+            }
+        }
+    }).unwrap();
+    let elapsed = start.elapsed().as_secs_f64();
+
+    total.latencies.sort();
+    println!("Messages sent:      {} ({:.1}/s)", total.sent, total.sent as f64 / elapsed);
+    println!("Messages received:  {} ({:.1}/s)", total.received, total.received as f64 / elapsed);
+    println!("Guesses:            {} ({:.1}/s)", total.guesses, total.guesses as f64 / elapsed);
+    println!("Rounds seen:        {} (won: {})", total.rounds, total.wins);
+    println!(
+        "Guess latency:      p50 {:?}, p90 {:?}, p99 {:?}, max {:?}",
+        percentile(&total.latencies, 50),
+        percentile(&total.latencies, 90),
+        percentile(&total.latencies, 99),
+        total.latencies.last().copied().unwrap_or_default(),
+    );
+    println!("Dropped (no reply): {}", total.unanswered);
+    println!("Errors:             {}", total.errors.len());
+    for error in &total.errors {
+        println!("  {}", error);
+    }
+    if !total.errors.is_empty() || total.unanswered > 0 {
+        process::exit(1);
+    }
+}
+
+// Tests unitaires pour l'outil de test de charge
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour le calcul des centiles
+    #[test]
+    fn test_percentile() {
+        let latencies: Vec<Duration> = (1..=10).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&latencies, 50), Duration::from_millis(5));
+        assert_eq!(percentile(&latencies, 90), Duration::from_millis(9));
+        assert_eq!(percentile(&latencies, 99), Duration::from_millis(10));
+        assert_eq!(percentile(&[], 50), Duration::ZERO);
+    }
+}
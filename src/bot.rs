@@ -0,0 +1,242 @@
+use std::str::FromStr;
+use std::time::Duration;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::protocol::{Difficulty, GamePhase, GuessError, Hint, ServerMessage};
+use crate::range::KnownRange;
+
+// Enumération représentant les stratégies de jeu des bots.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Strategy {
+    BinarySearch, // Propose toujours le milieu de l'intervalle encore possible.
+    Random, // Propose un nombre au hasard dans l'intervalle encore possible.
+    Human, // Vise le milieu de l'intervalle avec une erreur, et prend le temps de réfléchir.
+    Bad, // Ignore les indices et propose n'importe quel nombre de la plage.
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    // Permet de lire une stratégie depuis la ligne de commande ("binary", "random", "human" ou "bad").
+    fn from_str(value: &str) -> Result<Strategy, String> {
+        match value.to_lowercase().as_str() {
+            "binary" | "binary-search" => Ok(Strategy::BinarySearch),
+            "random" => Ok(Strategy::Random),
+            "human" => Ok(Strategy::Human),
+            "bad" => Ok(Strategy::Bad),
+            _ => Err(format!("unknown strategy: {}", value)),
+        }
+    }
+}
+
+// Action qu'un bot veut effectuer en réponse à un message du serveur.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    Vote(Difficulty), // Voter pour une difficulté.
+    Guess(u32), // Proposer un nombre.
+    Ready, // Se déclarer prêt dans le salon d'attente.
+}
+
+// `Bot` décide des actions d'un joueur automatique à partir des messages reçus du serveur.
+// Il ne fait aucune entrée/sortie : le programme qui l'utilise envoie les actions, après le délai de réflexion.
+pub struct Bot {
+    name: String, // Nom du bot dans la partie.
+    strategy: Strategy, // Stratégie de jeu.
+    vote: Option<Difficulty>, // Difficulté pour laquelle voter (None = au hasard).
+    rng: StdRng, // Générateur aléatoire (avec une graine, pour pouvoir rejouer une partie).
+    max: u32, // Borne supérieure de la plage de la manche en cours.
+    range: Option<KnownRange>, // Intervalle encore possible (None en dehors d'une manche, ou une fois le nombre trouvé).
+    pending: Option<u32>, // Devinette envoyée dont on attend l'indice.
+    turn_based: bool, // La manche se joue au tour par tour.
+    voted: bool, // Le bot a déjà voté pendant cette phase de vote.
+    ready: bool, // Le bot s'est déjà déclaré prêt dans le salon.
+}
+
+impl Bot {
+    // Crée un bot avec son nom, sa stratégie et la graine de son générateur aléatoire.
+    pub fn new(name: String, strategy: Strategy, seed: u64) -> Bot {
+        Bot {
+            name,
+            strategy,
+            vote: None,
+            rng: StdRng::seed_from_u64(seed),
+            max: Difficulty::Easy.max_number(),
+            range: None,
+            pending: None,
+            turn_based: false,
+            voted: false,
+            ready: false,
+        }
+    }
+
+    // Fixe la difficulté pour laquelle le bot vote (par défaut, il vote au hasard).
+    pub fn set_vote(&mut self, difficulty: Difficulty) {
+        self.vote = Some(difficulty);
+    }
+
+    // Renvoie le nom du bot.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // Nouvelle manche : le bot votera de nouveau à la prochaine phase de vote, et propose un premier nombre.
+    fn start_round(&mut self, difficulty: Difficulty) -> Option<Action> {
+        self.voted = false;
+        self.ready = false; // Il se déclarera prêt de nouveau au retour dans le salon.
+        self.max = difficulty.max_number();
+        self.range = Some(KnownRange::new(self.max));
+        self.pending = None;
+        self.turn_based = false;
+        self.next_guess()
+    }
+
+    // Met à jour l'état du bot à partir d'un message du serveur, et renvoie l'action à effectuer s'il y en a une.
+    pub fn handle(&mut self, message: &ServerMessage) -> Option<Action> {
+        match message {
+            ServerMessage::VotingCountdown(_) if !self.voted => {
+                self.voted = true;
+                let difficulty = self.vote.unwrap_or_else(|| {
+                    [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard][self.rng.gen_range(0..3)]
+                });
+                Some(Action::Vote(difficulty))
+            },
+            ServerMessage::Lobby { ready, .. } if !self.ready && !ready.contains(&self.name) => {
+                self.ready = true; // Un bot est toujours prêt.
+                Some(Action::Ready)
+            },
+            ServerMessage::GameStart(difficulty) => self.start_round(*difficulty),
+            // Arrivée pendant une manche : le bot la rejoint comme si elle commençait.
+            ServerMessage::GameState { phase: GamePhase::Playing, difficulty: Some(difficulty), .. } => self.start_round(*difficulty),
+            ServerMessage::Turn(player) => {
+                self.turn_based = true;
+                if *player == self.name && self.pending.is_none() {
+                    return self.next_guess();
+                }
+                None
+            },
+            ServerMessage::Hint(hints) => {
+                let guess = self.pending.take()?;
+                if hints.contains(&Hint::Correct) {
+                    self.range = None; // Nombre trouvé : plus rien à proposer pendant cette manche.
+                    return None;
+                }
+                if let Some(range) = self.range.as_mut() {
+                    range.apply(guess, hints);
+                }
+                if self.turn_based {
+                    return None; // Attend son prochain tour.
+                }
+                self.next_guess()
+            },
+            ServerMessage::GuessWithHint(guess, _, hints) => {
+                // Les indices partagés portent sur le même nombre secret.
+                if let Some(range) = self.range.as_mut() {
+                    range.apply(*guess, hints);
+                }
+                None
+            },
+            ServerMessage::GuessRejected(error) => {
+                self.pending = None;
+                if *error == GuessError::NotYourTurn {
+                    self.turn_based = true; // Attend l'annonce de son tour.
+                }
+                None
+            },
+            ServerMessage::GameEnd | ServerMessage::RoundTimeout { .. } | ServerMessage::RaceTimeout { .. } => {
+                self.range = None;
+                self.pending = None;
+                None
+            },
+            _ => None,
+        }
+    }
+
+    // Choisit la prochaine devinette selon la stratégie du bot.
+    fn next_guess(&mut self) -> Option<Action> {
+        let range = self.range?;
+        let guess = match self.strategy {
+            Strategy::BinarySearch => range.optimal_guess(),
+            Strategy::Random => self.rng.gen_range(range.low..=range.high),
+            Strategy::Human => {
+                // Vise le milieu, avec une erreur d'au plus un quart de l'intervalle.
+                let error = ((range.high - range.low) / 4) as i64;
+                let guess = range.optimal_guess() as i64 + self.rng.gen_range(-error..=error);
+                guess.clamp(range.low as i64, range.high as i64) as u32
+            },
+            Strategy::Bad => self.rng.gen_range(1..=self.max),
+        };
+        self.pending = Some(guess);
+        Some(Action::Guess(guess))
+    }
+
+    // Renvoie le temps de réflexion du bot avant d'effectuer une action.
+    pub fn think_time(&mut self) -> Duration {
+        match self.strategy {
+            Strategy::Human => Duration::from_millis(self.rng.gen_range(800..4000)),
+            _ => Duration::from_millis(self.rng.gen_range(100..400)),
+        }
+    }
+}
+
+// Tests unitaires pour le module bot
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Répond aux devinettes d'un bot jusqu'à ce qu'il trouve le nombre secret, et renvoie le nombre de tentatives.
+    fn play(bot: &mut Bot, secret: u32, max_attempts: u32) -> Option<u32> {
+        let mut action = bot.handle(&ServerMessage::GameStart(Difficulty::Hard));
+        for attempt in 1..=max_attempts {
+            let guess = match action {
+                Some(Action::Guess(guess)) => guess,
+                _ => return None,
+            };
+            let hint = if guess < secret { Hint::Higher } else if guess > secret { Hint::Lower } else { return Some(attempt) };
+            action = bot.handle(&ServerMessage::Hint(vec![hint]));
+        }
+        None
+    }
+
+    // Test pour la stratégie de recherche dichotomique, qui trouve toujours en au plus 10 tentatives sur 1000
+    #[test]
+    fn test_binary_search() {
+        let mut bot = Bot::new("Bot".to_string(), Strategy::BinarySearch, 1);
+        for secret in [1, 500, 777, 1000] {
+            assert!(play(&mut bot, secret, 10).is_some());
+        }
+    }
+
+    // Test pour les stratégies aléatoire et humaine, qui restent dans l'intervalle encore possible
+    #[test]
+    fn test_random_and_human_converge() {
+        for strategy in [Strategy::Random, Strategy::Human] {
+            let mut bot = Bot::new("Bot".to_string(), strategy, 7);
+            assert!(play(&mut bot, 321, 1000).is_some());
+        }
+    }
+
+    // Test pour le vote (une seule fois par phase de vote) et le tour par tour
+    #[test]
+    fn test_vote_and_turns() {
+        let mut bot = Bot::new("Bot".to_string(), Strategy::Bad, 3);
+        bot.set_vote(Difficulty::Medium);
+        let lobby = ServerMessage::Lobby { host: Some("Alice".to_string()), ready: vec![], min_players: 2, countdown: None };
+        assert_eq!(bot.handle(&lobby), Some(Action::Ready));
+        assert_eq!(bot.handle(&lobby), None);
+        assert_eq!(bot.handle(&ServerMessage::VotingCountdown(10)), Some(Action::Vote(Difficulty::Medium)));
+        assert_eq!(bot.handle(&ServerMessage::VotingCountdown(9)), None);
+        assert!(matches!(bot.handle(&ServerMessage::GameStart(Difficulty::Medium)), Some(Action::Guess(guess)) if (1..=500).contains(&guess)));
+        assert_eq!(bot.handle(&ServerMessage::GuessRejected(GuessError::NotYourTurn)), None);
+        assert_eq!(bot.handle(&ServerMessage::Turn("Alice".to_string())), None);
+        assert!(matches!(bot.handle(&ServerMessage::Turn("Bot".to_string())), Some(Action::Guess(_))));
+        assert_eq!(bot.handle(&ServerMessage::Hint(vec![Hint::Higher])), None);
+    }
+
+    // Test pour la lecture des stratégies
+    #[test]
+    fn test_parse_strategy() {
+        assert_eq!("binary".parse(), Ok(Strategy::BinarySearch));
+        assert_eq!("HUMAN".parse(), Ok(Strategy::Human));
+        assert!("smart".parse::<Strategy>().is_err());
+    }
+}
@@ -0,0 +1,209 @@
+use number_game::i18n::{self, MessageId};
+use number_game::protocol::{AdminCommand, Difficulty};
+
+// Commandes disponibles dans le client, avec leur syntaxe (utilisée pour l'aide, les erreurs d'usage et la complétion)
+// et le texte qui les décrit dans l'aide.
+pub const COMMANDS: [(&str, &str, MessageId); 17] = [
+    ("/players", "/players", MessageId::HelpPlayers),
+    ("/vote", "/vote <easy|medium|hard>", MessageId::HelpVote),
+    ("/leaderboard", "/leaderboard", MessageId::HelpLeaderboard),
+    ("/suggest", "/suggest", MessageId::HelpSuggest),
+    ("/chat", "/chat <message>", MessageId::HelpChat),
+    ("/whisper", "/whisper <player> <message>", MessageId::HelpWhisper),
+    ("/ready", "/ready", MessageId::HelpReady),
+    ("/start", "/start", MessageId::HelpStart),
+    ("/kick", "/kick <player>", MessageId::HelpKick),
+    ("/ban", "/ban <player|ip>", MessageId::HelpBan),
+    ("/forcestart", "/forcestart", MessageId::HelpForceStart),
+    ("/skipvote", "/skipvote", MessageId::HelpSkipVote),
+    ("/endround", "/endround", MessageId::HelpEndRound),
+    ("/difficulty", "/difficulty <easy|medium|hard>", MessageId::HelpDifficulty),
+    ("/host", "/host <player>", MessageId::HelpHost),
+    ("/quit", "/quit", MessageId::HelpQuit),
+    ("/help", "/help", MessageId::HelpHelp),
+];
+
+// Enumération représentant une commande tapée par le joueur.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Command {
+    Guess(u32), // Un nombre seul est une proposition.
+    Vote(Difficulty), // `/vote <niveau>`, ou un niveau seul.
+    Players, // `/players` : demande la liste des joueurs.
+    Leaderboard, // `/leaderboard` : demande le classement du match.
+    Suggest, // `/suggest` : affiche la proposition optimale.
+    Chat(String), // `/chat <message>`.
+    Whisper { to: String, text: String }, // `/whisper <joueur> <message>`.
+    Ready, // `/ready` : se déclare prêt dans le salon (ou ne l'est plus).
+    Start, // `/start` : l'hôte lance le match.
+    Admin(AdminCommand), // Commandes d'administration de l'hôte (`/kick`, `/ban`, `/difficulty`...).
+    Quit, // `/quit`.
+    Help, // `/help`.
+}
+
+// Enumération représentant les erreurs de saisie d'une commande.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CommandError {
+    Empty, // Rien n'a été saisi.
+    Invalid(String), // Ni un nombre, ni un niveau, ni une commande.
+    Unknown(String), // Commande inconnue.
+    Usage(&'static str), // Arguments manquants ou en trop : contient la syntaxe attendue.
+    UnknownLevel(String), // Niveau de difficulté inconnu.
+}
+
+// Analyse une ligne saisie par le joueur.
+pub fn parse(input: &str) -> Result<Command, CommandError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(CommandError::Empty);
+    }
+    if !input.starts_with('/') {
+        // Raccourcis : un nombre seul est une proposition, un niveau seul est un vote.
+        if let Ok(guess) = input.parse::<u32>() {
+            return Ok(Command::Guess(guess));
+        }
+        return match i18n::parse_difficulty(input) {
+            Some(difficulty) => Ok(Command::Vote(difficulty)),
+            None => Err(CommandError::Invalid(input.to_string())),
+        };
+    }
+
+    let (name, argument) = match input.split_once(char::is_whitespace) {
+        Some((name, argument)) => (name, argument.trim()),
+        None => (input, ""),
+    };
+    let command = match name.to_lowercase().as_str() {
+        "/players" => Command::Players,
+        "/vote" => match argument {
+            "" => return Err(CommandError::Usage(usage("/vote"))),
+            level => match i18n::parse_difficulty(level) {
+                Some(difficulty) => return Ok(Command::Vote(difficulty)),
+                None => return Err(CommandError::UnknownLevel(level.to_string())),
+            },
+        },
+        "/leaderboard" => Command::Leaderboard,
+        "/suggest" => Command::Suggest,
+        "/chat" => match argument {
+            "" => return Err(CommandError::Usage(usage("/chat"))),
+            text => return Ok(Command::Chat(text.to_string())),
+        },
+        "/whisper" | "/w" => match argument.split_once(char::is_whitespace) {
+            Some((to, text)) if !text.trim().is_empty() => return Ok(Command::Whisper { to: to.to_string(), text: text.trim().to_string() }),
+            _ => return Err(CommandError::Usage(usage("/whisper"))),
+        },
+        "/ready" => Command::Ready,
+        "/start" => Command::Start,
+        "/kick" if !argument.is_empty() => return Ok(Command::Admin(AdminCommand::Kick(argument.to_string()))),
+        "/ban" if !argument.is_empty() => return Ok(Command::Admin(AdminCommand::Ban(argument.to_string()))),
+        "/host" if !argument.is_empty() => return Ok(Command::Admin(AdminCommand::TransferHost(argument.to_string()))),
+        "/kick" | "/ban" | "/host" => return Err(CommandError::Usage(usage(name))),
+        "/forcestart" => Command::Admin(AdminCommand::ForceStart),
+        "/skipvote" => Command::Admin(AdminCommand::SkipVoting),
+        "/endround" => Command::Admin(AdminCommand::EndRound),
+        "/difficulty" => match argument {
+            "" => return Err(CommandError::Usage(usage("/difficulty"))),
+            level => match i18n::parse_difficulty(level) {
+                Some(difficulty) => return Ok(Command::Admin(AdminCommand::SetDifficulty(difficulty))),
+                None => return Err(CommandError::UnknownLevel(level.to_string())),
+            },
+        },
+        "/quit" => Command::Quit,
+        "/help" => Command::Help,
+        _ => return Err(CommandError::Unknown(name.to_string())),
+    };
+    if !argument.is_empty() {
+        return Err(CommandError::Usage(usage(name)));
+    }
+    Ok(command)
+}
+
+// Renvoie la syntaxe d'une commande.
+pub fn usage(name: &str) -> &'static str {
+    COMMANDS.iter().find(|(command, _, _)| command.eq_ignore_ascii_case(name)).map_or("/help", |(_, usage, _)| usage)
+}
+
+// Complète la ligne saisie (touche Tab) : le nom de la commande, ou le niveau de difficulté après `/vote` et `/difficulty`.
+// Renvoie None s'il n'y a rien à compléter.
+pub fn complete(input: &str) -> Option<String> {
+    for command in ["/vote", "/difficulty"] {
+        if let Some(level) = input.strip_prefix(command).and_then(|rest| rest.strip_prefix(' ')) {
+            let levels = ["easy", "medium", "hard", "facile", "moyen", "difficile"];
+            let completed = complete_from(level.trim_start(), &levels)?;
+            return Some(format!("{} {}", command, completed));
+        }
+    }
+    if !input.starts_with('/') || input.contains(char::is_whitespace) {
+        return None;
+    }
+    let names: Vec<&str> = COMMANDS.iter().map(|(name, _, _)| *name).collect();
+    let completed = complete_from(input, &names)?;
+    if names.contains(&completed.as_str()) && usage(&completed).contains('<') {
+        return Some(format!("{} ", completed)); // La commande attend un argument.
+    }
+    Some(completed)
+}
+
+// Complète un préfixe avec la plus longue partie commune des candidats qui commencent par lui.
+fn complete_from(prefix: &str, candidates: &[&str]) -> Option<String> {
+    let prefix = prefix.to_lowercase();
+    let matches: Vec<&str> = candidates.iter().copied().filter(|candidate| candidate.starts_with(&prefix)).collect();
+    let first = matches.first()?;
+    let common = matches.iter().fold(first.len(), |length, candidate| {
+        first.chars().zip(candidate.chars()).take(length).take_while(|(a, b)| a == b).count()
+    });
+    let completed: String = first.chars().take(common).collect();
+    if completed.len() > prefix.len() || matches.len() == 1 {
+        Some(completed)
+    } else {
+        None
+    }
+}
+
+// Tests unitaires pour le module command
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour les raccourcis (nombre seul, niveau seul)
+    #[test]
+    fn test_parse_shortcuts() {
+        assert_eq!(parse("42"), Ok(Command::Guess(42)));
+        assert_eq!(parse(" hard "), Ok(Command::Vote(Difficulty::Hard)));
+        assert_eq!(parse("moyen"), Ok(Command::Vote(Difficulty::Medium)));
+        assert_eq!(parse(""), Err(CommandError::Empty));
+        assert_eq!(parse("hello"), Err(CommandError::Invalid("hello".to_string())));
+    }
+
+    // Test pour les commandes
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(parse("/players"), Ok(Command::Players));
+        assert_eq!(parse("/vote easy"), Ok(Command::Vote(Difficulty::Easy)));
+        assert_eq!(parse("/chat hello  world"), Ok(Command::Chat("hello  world".to_string())));
+        assert_eq!(parse("/w Bob  psst"), Ok(Command::Whisper { to: "Bob".to_string(), text: "psst".to_string() }));
+        assert_eq!(parse("/whisper Bob"), Err(CommandError::Usage("/whisper <player> <message>")));
+        assert_eq!(parse("/QUIT"), Ok(Command::Quit));
+        assert_eq!(parse("/suggest"), Ok(Command::Suggest));
+        assert_eq!(parse("/vote"), Err(CommandError::Usage("/vote <easy|medium|hard>")));
+        assert_eq!(parse("/vote extreme"), Err(CommandError::UnknownLevel("extreme".to_string())));
+        assert_eq!(parse("/ready now"), Err(CommandError::Usage("/ready")));
+        assert_eq!(parse("/start"), Ok(Command::Start));
+        assert_eq!(parse("/kick Bob"), Ok(Command::Admin(AdminCommand::Kick("Bob".to_string()))));
+        assert_eq!(parse("/ban 10.0.0.7"), Ok(Command::Admin(AdminCommand::Ban("10.0.0.7".to_string()))));
+        assert_eq!(parse("/host"), Err(CommandError::Usage("/host <player>")));
+        assert_eq!(parse("/difficulty difficile"), Ok(Command::Admin(AdminCommand::SetDifficulty(Difficulty::Hard))));
+        assert_eq!(parse("/skipvote"), Ok(Command::Admin(AdminCommand::SkipVoting)));
+        assert_eq!(parse("/dance"), Err(CommandError::Unknown("/dance".to_string())));
+    }
+
+    // Test pour la complétion avec la touche Tab
+    #[test]
+    fn test_complete() {
+        assert_eq!(complete("/pl"), Some("/players".to_string()));
+        assert_eq!(complete("/vo"), Some("/vote ".to_string()));
+        assert_eq!(complete("/vote me"), Some("/vote medium".to_string()));
+        assert_eq!(complete("/vote "), None);
+        assert_eq!(complete("/difficulty f"), Some("/difficulty facile".to_string()));
+        assert_eq!(complete("/x"), None);
+        assert_eq!(complete("42"), None);
+    }
+}
@@ -0,0 +1,149 @@
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use number_game::game_client::GameClient;
+use number_game::i18n::{auth_error_text, tr, MessageId};
+use number_game::protocol::{Language, ServerMessage};
+use number_game::tls;
+mod command;
+mod tui;
+use command::{Command, CommandError};
+use tui::{InputEvent, TerminalGuard, Ui};
+
+fn main() {
+    let language = Language::from_env(); // Langue des textes affichés au joueur.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let spectate = args.iter().any(|arg| arg == "--spectate"); // Regarder la partie sans y participer.
+    let login = args.iter().any(|arg| arg == "--login"); // Se connecter à son compte pour utiliser son nom réservé.
+    let register = args.iter().any(|arg| arg == "--register"); // Créer un compte pour réserver son nom.
+    let ca = args.iter().position(|arg| arg == "--ca").and_then(|index| args.get(index + 1)); // Autorité de certification du serveur : active TLS.
+
+    // Connexion au serveur via TCP, chiffrée avec TLS si une autorité de certification est donnée
+    let connection = match ca {
+        Some(ca) => tls::client_config(Path::new(ca)).and_then(|config| GameClient::connect_tls("127.0.0.1:7878", "127.0.0.1", config)),
+        None => GameClient::connect("127.0.0.1:7878"),
+    };
+    let client = connection.unwrap_or_else(|error| connection_failed(language, error));
+
+    // Lecture du nom du joueur à partir de l'entrée standard.
+    let mut player_name = String::new();
+    println!("{}", tr(language, MessageId::EnterName, &[]));
+    io::stdin().read_line(&mut player_name).unwrap();
+    player_name = player_name.trim().to_string(); // Suppression des espaces superflus.
+
+    if login || register {
+        // Le mot de passe est lu sans être affiché, puis le client attend la réponse du serveur avant de rejoindre la partie.
+        let password = rpassword::prompt_password(tr(language, MessageId::EnterPassword, &[])).expect("Failed to read the password");
+        if register {
+            client.register(&player_name, &password).unwrap_or_else(|error| connection_failed(language, error));
+        } else {
+            client.login(&player_name, &password).unwrap_or_else(|error| connection_failed(language, error));
+        }
+        match client.next_event(Duration::from_secs(30)) {
+            Some(ServerMessage::Authenticated(name)) => println!("{}", tr(language, MessageId::LoggedIn, &[&name])),
+            Some(ServerMessage::AuthRejected(error)) => {
+                eprintln!("{}", auth_error_text(language, error));
+                std::process::exit(1);
+            },
+            _ => {
+                eprintln!("{}", tr(language, MessageId::ConnectionFailed, &[]));
+                std::process::exit(1);
+            },
+        }
+    }
+
+    // Envoi d'un message de type `Join` (ou `JoinAsSpectator`) au serveur pour signaler l'entrée du joueur.
+    let joined = if spectate {
+        client.join_as_spectator(&player_name, language)
+    } else {
+        client.join(&player_name, language)
+    };
+    joined.unwrap_or_else(|error| connection_failed(language, error));
+
+    // Interface plein écran : l'état affiché est partagé entre le thread de lecture et la saisie du joueur.
+    let ui = Arc::new(Mutex::new(Ui::new(language, player_name.clone())));
+    if spectate {
+        ui.lock().unwrap().log_tr(MessageId::Spectating, &[]);
+    }
+    let terminal = TerminalGuard::enter().expect("Failed to set up the terminal");
+    ui.lock().unwrap().render(&mut io::stdout()).ok();
+
+    let reader_ui = Arc::clone(&ui);
+    let events = client.events().clone();
+    let handle = std::thread::spawn(move || {
+        // Mise à jour de l'interface pour chaque message reçu, puis affichage, jusqu'à la fin de la connexion.
+        for message in events.iter() {
+            let mut ui = reader_ui.lock().unwrap();
+            ui.apply(message);
+            ui.render(&mut io::stdout()).ok();
+        }
+    });
+
+    // Boucle principale pour traiter l'entrée du joueur, jusqu'à ce qu'il quitte le jeu ou que la connexion soit fermée
+    // (arrêt du serveur, exclusion...). L'attente d'une touche est courte pour que le minuteur affiché reste à jour.
+    let mut disconnected = false;
+    while let Ok(event) = tui::read_input(&ui, Duration::from_millis(200)) {
+        if handle.is_finished() {
+            disconnected = true; // Le serveur a fermé la connexion : le flux des messages reçus est terminé.
+            break;
+        }
+        let mut ui = ui.lock().unwrap();
+        let sent = match event {
+            Some(InputEvent::Quit) => break, // Le joueur quitte le jeu.
+            Some(InputEvent::Line(input)) => match command::parse(&input) {
+                // Un nombre seul est une supposition.
+                Ok(Command::Guess(guess)) => client.guess(guess).map(|_| ui.guess_sent(guess)),
+                // Un niveau de difficulté (en français ou en anglais), seul ou après `/vote`, est un vote.
+                Ok(Command::Vote(difficulty)) => client.vote(difficulty),
+                Ok(Command::Players) => client.request_players().map(|_| ui.players_requested()),
+                Ok(Command::Leaderboard) => client.request_leaderboard(),
+                Ok(Command::Suggest) => { ui.suggest(); Ok(()) },
+                Ok(Command::Chat(text)) => client.chat(&text),
+                Ok(Command::Whisper { to, text }) => client.whisper(&to, &text),
+                Ok(Command::Ready) => client.ready(),
+                Ok(Command::Start) => client.start_match(),
+                Ok(Command::Admin(command)) => client.admin(command),
+                Ok(Command::Quit) => break, // Le joueur quitte le jeu.
+                Ok(Command::Help) => { show_help(&mut ui); Ok(()) },
+                Err(CommandError::Empty) => Ok(()),
+                Err(CommandError::Invalid(_)) => { ui.log_tr(MessageId::InvalidInput, &[]); Ok(()) }, // Gestion des entrées invalides.
+                Err(CommandError::Unknown(name)) => { ui.log_tr(MessageId::UnknownCommand, &[&name]); Ok(()) },
+                Err(CommandError::Usage(usage)) => { ui.log_tr(MessageId::UsageError, &[&usage]); Ok(()) },
+                Err(CommandError::UnknownLevel(level)) => { ui.log_tr(MessageId::UnknownLevel, &[&level]); Ok(()) },
+            },
+            None => Ok(()),
+        };
+        if sent.is_err() {
+            disconnected = true; // L'envoi a échoué : la connexion est fermée.
+            break;
+        }
+        ui.render(&mut io::stdout()).ok();
+    }
+
+    drop(terminal); // Restaure le terminal avant de quitter.
+    client.close(); // Ferme la connexion, ce qui termine le flux des messages reçus.
+    handle.join().unwrap(); // Attente de la fin du thread secondaire avant de terminer le programme principal.
+    if disconnected {
+        // Les derniers événements (arrêt du serveur, exclusion...) restent lisibles une fois l'interface fermée.
+        for line in ui.lock().unwrap().recent_events(3) {
+            println!("{}", line);
+        }
+        println!("{}", tr(language, MessageId::Disconnected, &[]));
+    }
+}
+
+// Quitte le client quand la connexion au serveur n'a pas pu être établie ou a été perdue avant le début de la partie.
+fn connection_failed(language: Language, error: io::Error) -> ! {
+    eprintln!("{} ({})", tr(language, MessageId::ConnectionFailed, &[]), error);
+    std::process::exit(1);
+}
+
+// Affiche l'aide des commandes dans le journal.
+fn show_help(ui: &mut Ui) {
+    ui.log_tr(MessageId::HelpHeader, &[]);
+    ui.log_tr(MessageId::HelpGuess, &[]);
+    for (_, usage, help) in command::COMMANDS {
+        ui.log_tr(help, &[&usage]);
+    }
+}
@@ -0,0 +1,722 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Write};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use crate::command;
+use number_game::i18n::{self, tr, Language, MessageId};
+use number_game::protocol::{AdminCommand, AdminError, ChatError, Difficulty, GamePhase, GuessError, Hint, ServerMessage};
+use number_game::range::KnownRange;
+
+const MAX_LINES: usize = 200; // Nombre maximal de lignes conservées dans l'historique et le journal.
+const LEFT_WIDTH: usize = 26; // Largeur de la colonne de gauche (joueurs, votes, minuteur).
+
+// `Ui` contient l'état affiché par l'interface plein écran du client.
+// Les messages du serveur mettent à jour cet état, puis l'écran est entièrement redessiné à partir de lui.
+pub struct Ui {
+    language: Language, // Langue des textes affichés.
+    player_name: String, // Nom du joueur, pour le distinguer dans la liste des joueurs.
+    players: Vec<String>, // Joueurs connectés.
+    spectators: Vec<String>, // Spectateurs connectés.
+    waiting: Vec<String>, // Joueurs arrivés pendant la manche, qui joueront à partir de la suivante.
+    host: Option<String>, // Hôte du salon d'attente.
+    ready: Vec<String>, // Joueurs prêts dans le salon d'attente.
+    in_lobby: bool, // La partie attend dans le salon que les joueurs soient prêts.
+    voting: bool, // Une phase de vote est en cours.
+    votes: HashMap<Difficulty, u32>, // Décompte des votes de difficulté de la phase de vote en cours.
+    timer: Option<(MessageId, Instant)>, // Minuteur affiché (vote ou manche) et instant de son expiration.
+    pending_guesses: VecDeque<u32>, // Devinettes envoyées dont on attend encore l'indice.
+    history: Vec<String>, // Historique de nos devinettes avec les indices reçus.
+    events: Vec<String>, // Journal des événements de la partie (devinettes des autres joueurs, résultats...).
+    range: Option<KnownRange>, // Intervalle encore possible pour le nombre secret pendant la manche en cours.
+    pub input: String, // Ligne en cours de saisie.
+    players_requested: bool, // Le joueur a demandé la liste des joueurs avec `/players` : elle sera aussi affichée dans le journal.
+}
+
+// Action demandée par le joueur depuis la ligne de saisie.
+pub enum InputEvent {
+    Line(String), // Le joueur a validé une ligne.
+    Quit, // Le joueur veut quitter le jeu.
+}
+
+impl Ui {
+    // Crée une interface vide pour le joueur donné.
+    pub fn new(language: Language, player_name: String) -> Ui {
+        Ui {
+            language,
+            player_name,
+            players: vec![],
+            spectators: vec![],
+            waiting: vec![],
+            host: None,
+            ready: vec![],
+            in_lobby: false,
+            voting: false,
+            votes: HashMap::new(),
+            timer: None,
+            pending_guesses: VecDeque::new(),
+            history: vec![],
+            events: vec![],
+            range: None,
+            input: String::new(),
+            players_requested: false,
+        }
+    }
+
+    // Ajoute une ligne au journal des événements.
+    pub fn log(&mut self, line: String) {
+        push_line(&mut self.events, line);
+    }
+
+    // Ajoute une ligne traduite au journal des événements.
+    pub fn log_tr(&mut self, id: MessageId, args: &[&dyn std::fmt::Display]) {
+        let line = tr(self.language, id, args);
+        self.log(line);
+    }
+
+    // Renvoie les dernières lignes du journal des événements.
+    pub fn recent_events(&self, count: usize) -> Vec<String> {
+        last_lines(&self.events, count)
+    }
+
+    // Indique que le joueur a demandé la liste des joueurs, pour l'afficher dans le journal à sa réception.
+    pub fn players_requested(&mut self) {
+        self.players_requested = true;
+    }
+
+    // Mémorise une devinette envoyée au serveur, pour l'associer à l'indice qui sera reçu.
+    // Avertit le joueur si la devinette est hors de l'intervalle encore possible.
+    pub fn guess_sent(&mut self, guess: u32) {
+        if let Some(range) = self.range.filter(|range| !range.contains(guess)) {
+            self.log_tr(MessageId::GuessOutsideRange, &[&guess, &range.low, &range.high]);
+        }
+        self.pending_guesses.push_back(guess);
+    }
+
+    // Affiche dans le journal la devinette optimale pour l'intervalle encore possible (aide à l'entraînement).
+    pub fn suggest(&mut self) {
+        match self.range {
+            Some(range) => self.log_tr(MessageId::SuggestedGuess, &[&range.optimal_guess(), &range.low, &range.high]),
+            None => self.log_tr(MessageId::NoRange, &[]),
+        }
+    }
+
+    // Resserre l'intervalle encore possible à partir des indices d'une devinette.
+    fn narrow_range(&mut self, guess: u32, hints: &[Hint]) {
+        if let Some(range) = self.range.as_mut() {
+            range.apply(guess, hints);
+        }
+    }
+
+    // Indique si le client regarde la partie en spectateur.
+    fn spectating(&self) -> bool {
+        self.spectators.contains(&self.player_name)
+    }
+
+    // Met en forme une liste d'indices sur une seule ligne.
+    fn format_hints(&self, hints: &[Hint]) -> String {
+        hints.iter().map(|hint| i18n::hint_text(self.language, hint)).collect::<Vec<String>>().join(", ")
+    }
+
+    // Met à jour l'état de l'interface à partir d'un message du serveur.
+    pub fn apply(&mut self, message: ServerMessage) {
+        match message {
+            ServerMessage::Hint(hints) => {
+                // Associe les indices reçus à la devinette correspondante dans l'historique.
+                let text = self.format_hints(&hints);
+                let line = match self.pending_guesses.pop_front() {
+                    Some(guess) => {
+                        self.narrow_range(guess, &hints);
+                        tr(self.language, MessageId::GuessHistoryLine, &[&guess, &text])
+                    },
+                    None => tr(self.language, MessageId::HintReceived, &[&text]),
+                };
+                push_line(&mut self.history, line);
+            },
+            ServerMessage::PlayerWon(winner) => self.log_tr(MessageId::RoundWon, &[&winner]),
+            ServerMessage::GameStart(difficulty) => {
+                // Nouvelle manche : les votes et l'historique de la manche précédente ne sont plus utiles.
+                self.votes.clear();
+                self.voting = false;
+                self.history.clear();
+                self.pending_guesses.clear();
+                self.timer = None;
+                self.range = Some(KnownRange::new(difficulty.max_number()));
+                let difficulty = i18n::difficulty_text(self.language, difficulty);
+                self.log_tr(MessageId::GameStarted, &[&difficulty]);
+            },
+            ServerMessage::TimerStart(seconds) => {
+                self.start_timer(MessageId::RoundTimer, seconds);
+                self.log_tr(MessageId::TimerStarted, &[&seconds]);
+            },
+            ServerMessage::TimeRemaining(seconds) => self.start_timer(MessageId::RoundTimer, seconds),
+            ServerMessage::VotingCountdown(seconds) => {
+                if !self.voting {
+                    // Début de la phase de vote : on quitte le salon, et le joueur est invité à voter.
+                    self.voting = true;
+                    self.in_lobby = false;
+                    self.ready.clear();
+                    if !self.spectating() {
+                        self.log_tr(MessageId::VotePrompt, &[]);
+                    }
+                }
+                self.start_timer(MessageId::VotingTimer, seconds);
+            },
+            ServerMessage::Lobby { host, ready, min_players, countdown } => {
+                if !self.in_lobby {
+                    self.in_lobby = true;
+                    if !self.spectating() {
+                        self.log_tr(MessageId::LobbyPrompt, &[&min_players]);
+                    }
+                }
+                self.host = host;
+                self.ready = ready;
+                match countdown {
+                    Some(seconds) => self.start_timer(MessageId::LobbyTimer, seconds),
+                    None => self.timer = None, // Quelqu'un n'est plus prêt : le lancement automatique est annulé.
+                }
+            },
+            ServerMessage::RoundTimeout { secret } => {
+                self.timer = None;
+                self.log_tr(MessageId::RoundTimeout, &[&secret]);
+            },
+            ServerMessage::Guess(value, player) => self.log_tr(MessageId::PlayerGuessed, &[&player, &value]),
+            ServerMessage::GuessWithHint(value, player, hints) => {
+                // Les indices partagés portent sur le même nombre secret : ils resserrent aussi notre intervalle.
+                self.narrow_range(value, &hints);
+                let text = self.format_hints(&hints);
+                self.log_tr(MessageId::PlayerGuessedWithHint, &[&player, &value, &text]);
+            },
+            ServerMessage::RaceProgress(player, attempts) => self.log_tr(MessageId::RaceProgress, &[&player, &attempts]),
+            ServerMessage::RaceTimeout { secrets } => {
+                self.timer = None;
+                self.log_tr(MessageId::RaceTimeout, &[]);
+                for (player, secret) in secrets {
+                    self.log_tr(MessageId::RaceSecret, &[&player, &secret]);
+                }
+            },
+            ServerMessage::Turn(player) => {
+                if player == self.player_name {
+                    self.log_tr(MessageId::YourTurn, &[]);
+                } else {
+                    self.log_tr(MessageId::OtherTurn, &[&player]);
+                }
+            },
+            ServerMessage::TurnSkipped(player) => self.log_tr(MessageId::TurnSkipped, &[&player]),
+            ServerMessage::GuessRejected(error) => {
+                self.pending_guesses.pop_front(); // Cette devinette ne recevra pas d'indice.
+                let id = match error {
+                    GuessError::WrongPhase => MessageId::GuessWrongPhase,
+                    GuessError::NotYourTurn => MessageId::GuessNotYourTurn,
+                    GuessError::Spectating => MessageId::GuessSpectating,
+                    GuessError::NextRound => MessageId::GuessNextRound,
+                };
+                self.log_tr(id, &[]);
+            },
+            ServerMessage::Notice(text) => self.log(text),
+            ServerMessage::GameEnd => {
+                self.timer = None;
+                self.range = None;
+                self.log_tr(MessageId::RoundEnded, &[]);
+            },
+            ServerMessage::Standings { round, rounds, standings } => {
+                self.log_tr(MessageId::StandingsHeader, &[&round, &rounds]);
+                self.log_standings(&standings);
+                if round < rounds {
+                    self.log_tr(MessageId::NextRoundVote, &[]);
+                }
+            },
+            ServerMessage::MatchEnd { standings, winner } => {
+                match winner {
+                    Some(winner) => self.log_tr(MessageId::MatchWon, &[&winner]),
+                    None => self.log_tr(MessageId::MatchDraw, &[]),
+                }
+                self.log_standings(&standings);
+                self.log_tr(MessageId::NewMatch, &[]);
+            },
+            ServerMessage::DifficultyVote(difficulty) => *self.votes.entry(difficulty).or_insert(0) += 1,
+            ServerMessage::PlayerList { players, spectators, waiting } => {
+                if std::mem::take(&mut self.players_requested) {
+                    self.log_tr(MessageId::PlayersLine, &[&players.join(", ")]);
+                    if !spectators.is_empty() {
+                        self.log_tr(MessageId::SpectatorsLine, &[&spectators.join(", ")]);
+                    }
+                    if !waiting.is_empty() {
+                        self.log_tr(MessageId::WaitingLine, &[&waiting.join(", ")]);
+                    }
+                }
+                self.players = players;
+                self.spectators = spectators;
+                self.waiting = waiting;
+            },
+            ServerMessage::GameState { phase, difficulty, round, rounds, remaining } => match (phase, difficulty) {
+                // Arrivée en cours de partie : affiche la phase en cours et son minuteur.
+                (GamePhase::Playing, Some(difficulty)) => {
+                    self.range = Some(KnownRange::new(difficulty.max_number()));
+                    if let Some(seconds) = remaining {
+                        self.start_timer(MessageId::RoundTimer, seconds);
+                    }
+                    let difficulty = i18n::difficulty_text(self.language, difficulty);
+                    self.log_tr(MessageId::RoundInProgress, &[&round, &rounds, &difficulty]);
+                },
+                (GamePhase::Voting, _) => {
+                    if let Some(seconds) = remaining {
+                        self.start_timer(MessageId::VotingTimer, seconds);
+                    }
+                    self.log_tr(MessageId::VotingInProgress, &[&round, &rounds]);
+                },
+                _ => {},
+            },
+            ServerMessage::SecretRevealed(secret) => self.log_tr(MessageId::SecretRevealed, &[&secret]),
+            ServerMessage::ChatMessage { from, text, timestamp } => self.log_tr(MessageId::ChatLine, &[&clock(timestamp), &from, &text]),
+            ServerMessage::Whisper { from, to, text, timestamp } => {
+                if from == self.player_name {
+                    self.log_tr(MessageId::WhisperSent, &[&clock(timestamp), &to, &text]);
+                } else {
+                    self.log_tr(MessageId::WhisperReceived, &[&clock(timestamp), &from, &text]);
+                }
+            },
+            ServerMessage::ChatRejected(error) => match error {
+                ChatError::Empty => self.log_tr(MessageId::ChatEmpty, &[]),
+                ChatError::TooLong(max) => self.log_tr(MessageId::ChatTooLong, &[&max]),
+                ChatError::RateLimited => self.log_tr(MessageId::ChatRateLimited, &[]),
+                ChatError::Blocked => self.log_tr(MessageId::ChatBlocked, &[]),
+                ChatError::UnknownRecipient => self.log_tr(MessageId::ChatUnknownRecipient, &[]),
+            },
+            ServerMessage::Leaderboard(standings) => {
+                self.log_tr(MessageId::LeaderboardHeader, &[]);
+                self.log_standings(&standings);
+            },
+            ServerMessage::AdminAction { by, action } => {
+                let by = by.unwrap_or_else(|| tr(self.language, MessageId::ServerName, &[]));
+                match action {
+                    AdminCommand::Kick(name) if name == self.player_name => self.log_tr(MessageId::Kicked, &[&by]),
+                    AdminCommand::Kick(name) => self.log_tr(MessageId::ActionKick, &[&name, &by]),
+                    AdminCommand::Ban(name) if name == self.player_name => self.log_tr(MessageId::Banned, &[]),
+                    AdminCommand::Ban(name) => self.log_tr(MessageId::ActionBan, &[&name, &by]),
+                    AdminCommand::ForceStart => self.log_tr(MessageId::ActionForceStart, &[&by]),
+                    AdminCommand::SkipVoting => self.log_tr(MessageId::ActionSkipVoting, &[&by]),
+                    AdminCommand::EndRound => self.log_tr(MessageId::ActionEndRound, &[&by]),
+                    AdminCommand::SetDifficulty(difficulty) => {
+                        let difficulty = i18n::difficulty_text(self.language, difficulty);
+                        self.log_tr(MessageId::ActionDifficulty, &[&difficulty, &by]);
+                    },
+                    AdminCommand::TransferHost(name) => {
+                        self.log_tr(MessageId::ActionHost, &[&name, &by]);
+                        self.host = Some(name);
+                    },
+                }
+            },
+            ServerMessage::ServerShutdown { reason, seconds } => {
+                match reason {
+                    Some(reason) => self.log_tr(MessageId::ServerShutdownReason, &[&seconds, &reason]),
+                    None => self.log_tr(MessageId::ServerShutdown, &[&seconds]),
+                }
+            },
+            ServerMessage::Announcement(text) => self.log_tr(MessageId::Announcement, &[&text]),
+            ServerMessage::Authenticated(name) => self.log_tr(MessageId::LoggedIn, &[&name]),
+            ServerMessage::AuthRejected(error) => {
+                let text = i18n::auth_error_text(self.language, error);
+                self.log(text);
+            },
+            ServerMessage::AdminRejected(error) => {
+                let id = match error {
+                    AdminError::NotHost => MessageId::AdminNotHost,
+                    AdminError::WrongPhase => MessageId::AdminWrongPhase,
+                    AdminError::UnknownPlayer => MessageId::AdminUnknownPlayer,
+                    AdminError::OwnPlayer => MessageId::AdminOwnPlayer,
+                    AdminError::NoPlayers => MessageId::AdminNoPlayers,
+                };
+                self.log_tr(id, &[]);
+            },
+        }
+    }
+
+    // Ajoute un classement au journal, une ligne par joueur.
+    fn log_standings(&mut self, standings: &[(String, u32)]) {
+        for (rank, (name, score)) in standings.iter().enumerate() {
+            self.log_tr(MessageId::StandingsLine, &[&(rank + 1), name, score]);
+        }
+    }
+
+    // Démarre (ou resynchronise) le minuteur affiché.
+    fn start_timer(&mut self, label: MessageId, seconds: u32) {
+        self.timer = Some((label, Instant::now() + Duration::from_secs(seconds as u64)));
+    }
+
+    // Renvoie le texte du minuteur affiché (None s'il n'y a pas de minuteur en cours).
+    pub fn timer_text(&self) -> Option<String> {
+        let (label, deadline) = self.timer?;
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let seconds = remaining.as_secs() + if remaining.subsec_nanos() > 0 { 1 } else { 0 };
+        Some(tr(self.language, label, &[&seconds]))
+    }
+
+    // Compose les lignes de l'écran pour un terminal de la taille donnée.
+    // La dernière ligne est la ligne de saisie, séparée du reste par une ligne horizontale.
+    pub fn lines(&self, width: usize, height: usize) -> Vec<String> {
+        let body_height = height.saturating_sub(2);
+        let left_width = LEFT_WIDTH.min(width / 3);
+        let right_width = width.saturating_sub(left_width + 1);
+
+        // Colonne de gauche : joueurs, votes et minuteur.
+        let mut left = vec![title(&tr(self.language, MessageId::PanePlayers, &[]))];
+        for player in &self.players {
+            let marker = if *player == self.player_name { "*" } else { " " };
+            // Dans le salon, l'hôte est marqué d'une étoile et les joueurs prêts d'une coche.
+            let host = if self.in_lobby && self.host.as_ref() == Some(player) { " ★" } else { "" };
+            let ready = if self.in_lobby && self.ready.contains(player) { " ✓" } else { "" };
+            left.push(format!("{}{}{}{}", marker, player, host, ready));
+        }
+        if !self.spectators.is_empty() {
+            // Les spectateurs sont affichés à part, sous les joueurs.
+            left.push(String::new());
+            left.push(title(&tr(self.language, MessageId::PaneSpectators, &[])));
+            for spectator in &self.spectators {
+                let marker = if *spectator == self.player_name { "*" } else { " " };
+                left.push(format!("{}{}", marker, spectator));
+            }
+        }
+        if !self.waiting.is_empty() {
+            // Les joueurs qui attendent la manche suivante sont aussi affichés à part.
+            left.push(String::new());
+            left.push(title(&tr(self.language, MessageId::PaneWaiting, &[])));
+            for player in &self.waiting {
+                let marker = if *player == self.player_name { "*" } else { " " };
+                left.push(format!("{}{}", marker, player));
+            }
+        }
+        left.push(String::new());
+        left.push(title(&tr(self.language, MessageId::PaneVotes, &[])));
+        for difficulty in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+            let votes = self.votes.get(&difficulty).copied().unwrap_or(0);
+            left.push(format!(" {}: {}", i18n::difficulty_text(self.language, difficulty), votes));
+        }
+        left.push(String::new());
+        left.push(title(&tr(self.language, MessageId::PaneTimer, &[])));
+        left.push(format!(" {}", self.timer_text().unwrap_or_else(|| "-".to_string())));
+        left.push(String::new());
+        left.push(title(&tr(self.language, MessageId::PaneRange, &[])));
+        left.push(match self.range {
+            Some(range) => format!(" {} – {}", range.low, range.high),
+            None => " -".to_string(),
+        });
+
+        // Colonne de droite : nos devinettes en haut, le journal des événements en bas.
+        let guesses_height = body_height / 2;
+        let mut right = vec![title(&tr(self.language, MessageId::PaneGuesses, &[]))];
+        right.extend(last_lines(&self.history, guesses_height.saturating_sub(1)));
+        right.resize(guesses_height, String::new());
+        right.push(title(&tr(self.language, MessageId::PaneEvents, &[])));
+        right.extend(last_lines(&self.events, body_height.saturating_sub(guesses_height + 1)));
+
+        let mut lines: Vec<String> = (0..body_height).map(|row| {
+            let left = left.get(row).map(String::as_str).unwrap_or("");
+            let right = right.get(row).map(String::as_str).unwrap_or("");
+            format!("{}│{}", fit(left, left_width), fit(right, right_width))
+        }).collect();
+        lines.push("─".repeat(width));
+        lines.push(self.input_line(width.saturating_sub(1)));
+        lines
+    }
+
+    // Compose la ligne de saisie : l'invite, la fin du texte saisi et le rappel de la touche pour quitter.
+    fn input_line(&self, width: usize) -> String {
+        let quit_hint = tr(self.language, MessageId::QuitHint, &[]);
+        let prompt = self.prompt(width);
+        let padding = width.saturating_sub(prompt.width() + quit_hint.width());
+        fit(&format!("{}{}{}", prompt, " ".repeat(padding), quit_hint), width)
+    }
+
+    // Invite suivie de la fin du texte saisi, qui tient dans la place laissée par le rappel de la touche pour quitter.
+    fn prompt(&self, width: usize) -> String {
+        let quit_hint = tr(self.language, MessageId::QuitHint, &[]);
+        let available = width.saturating_sub(quit_hint.width() + 3);
+        format!("> {}", tail(&self.input, available))
+    }
+
+    // Colonne du curseur : juste après le texte saisi affiché, mesuré en colonnes de l'écran.
+    fn cursor_column(&self, width: usize) -> usize {
+        self.prompt(width).width().min(width.saturating_sub(1))
+    }
+
+    // Redessine tout l'écran. Chaque ligne est réécrite en entier, ce qui évite de l'effacer (et le scintillement).
+    pub fn render(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let lines = self.lines(width as usize, height as usize);
+        for (row, line) in lines.iter().enumerate() {
+            queue!(out, MoveTo(0, row as u16), Print(line))?;
+        }
+        let cursor = self.cursor_column(width as usize);
+        queue!(out, MoveTo(cursor as u16, height.saturating_sub(1)), Show)?;
+        out.flush()
+    }
+}
+
+// Met en forme l'heure d'un message du chat (heures et minutes, UTC).
+fn clock(timestamp: u64) -> String {
+    format!("{:02}:{:02}", timestamp / 3600 % 24, timestamp / 60 % 60)
+}
+
+// Ajoute une ligne à une liste en ne gardant que les `MAX_LINES` dernières.
+fn push_line(lines: &mut Vec<String>, line: String) {
+    lines.push(line);
+    if lines.len() > MAX_LINES {
+        lines.remove(0);
+    }
+}
+
+// Renvoie les `count` dernières lignes d'une liste.
+fn last_lines(lines: &[String], count: usize) -> Vec<String> {
+    lines[lines.len().saturating_sub(count)..].to_vec()
+}
+
+// Met en forme le titre d'un panneau.
+fn title(text: &str) -> String {
+    format!("─ {} ─", text)
+}
+
+// Tronque ou complète un texte avec des espaces pour qu'il occupe exactement `width` colonnes de l'écran.
+fn fit(text: &str, width: usize) -> String {
+    let mut fitted = String::new();
+    let mut used = 0; // Colonnes occupées : un caractère large en prend deux, un accent combiné aucune.
+    for c in text.chars() {
+        let columns = c.width().unwrap_or(0);
+        if used + columns > width {
+            break;
+        }
+        fitted.push(c);
+        used += columns;
+    }
+    fitted.push_str(&" ".repeat(width - used));
+    fitted
+}
+
+// Renvoie la fin d'un texte qui tient dans la largeur donnée (en colonnes de l'écran).
+fn tail(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    let mut start = text.len();
+    for (index, c) in text.char_indices().rev() {
+        let columns = c.width().unwrap_or(0);
+        if used + columns > width {
+            break;
+        }
+        used += columns;
+        start = index;
+    }
+    &text[start..]
+}
+
+// Passe le terminal en mode plein écran. Le terminal est restauré quand la valeur renvoyée est détruite.
+pub struct TerminalGuard;
+
+impl TerminalGuard {
+    pub fn enter() -> io::Result<TerminalGuard> {
+        terminal::enable_raw_mode()?; // Les touches sont lues une par une, sans écho.
+        execute!(io::stdout(), EnterAlternateScreen, Clear(ClearType::All), Hide)?;
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        execute!(io::stdout(), Show, LeaveAlternateScreen).ok();
+        terminal::disable_raw_mode().ok();
+    }
+}
+
+// Attend une touche pendant au plus `timeout` et met à jour la ligne de saisie.
+// L'interface n'est verrouillée qu'une fois la touche reçue, pour ne pas bloquer l'affichage des messages du serveur.
+// Renvoie l'action demandée quand le joueur valide une ligne ou veut quitter.
+pub fn read_input(ui: &Mutex<Ui>, timeout: Duration) -> io::Result<Option<InputEvent>> {
+    if !event::poll(timeout)? {
+        return Ok(None);
+    }
+    if let Event::Key(key) = event::read()? {
+        let mut ui = ui.lock().unwrap();
+        match key.code {
+            KeyCode::Esc => return Ok(Some(InputEvent::Quit)),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(Some(InputEvent::Quit)),
+            KeyCode::Char(c) => ui.input.push(c),
+            KeyCode::Tab => {
+                // Complétion de la commande ou du niveau de difficulté.
+                if let Some(completed) = command::complete(&ui.input) {
+                    ui.input = completed;
+                }
+            },
+            KeyCode::Backspace => {
+                ui.input.pop();
+            },
+            KeyCode::Enter => {
+                let line = std::mem::take(&mut ui.input);
+                return Ok(Some(InputEvent::Line(line)));
+            },
+            _ => {},
+        }
+    }
+    Ok(None)
+}
+
+// Tests unitaires pour le module tui
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour l'association des indices reçus aux devinettes envoyées
+    #[test]
+    fn test_hint_history() {
+        let mut ui = Ui::new(Language::English, "Alice".to_string());
+        ui.guess_sent(40);
+        ui.guess_sent(60);
+        ui.apply(ServerMessage::Hint(vec![Hint::Higher]));
+        ui.apply(ServerMessage::GuessRejected(GuessError::NotYourTurn));
+        assert_eq!(ui.history, vec!["40 → Higher".to_string()]);
+        assert!(ui.pending_guesses.is_empty());
+        assert_eq!(ui.events, vec!["It's not your turn, please wait.".to_string()]);
+    }
+
+    // Test pour le décompte des votes
+    #[test]
+    fn test_vote_tally() {
+        let mut ui = Ui::new(Language::English, "Alice".to_string());
+        ui.apply(ServerMessage::DifficultyVote(Difficulty::Hard));
+        ui.apply(ServerMessage::DifficultyVote(Difficulty::Hard));
+        assert_eq!(ui.votes.get(&Difficulty::Hard), Some(&2));
+        ui.apply(ServerMessage::GameStart(Difficulty::Hard));
+        assert!(ui.votes.is_empty());
+    }
+
+    // Test pour l'affichage de la liste des joueurs demandée avec `/players`
+    #[test]
+    fn test_requested_player_list() {
+        let mut ui = Ui::new(Language::English, "Alice".to_string());
+        ui.apply(ServerMessage::PlayerList { players: vec!["Alice".to_string()], spectators: vec![], waiting: vec![] });
+        assert!(ui.events.is_empty());
+        ui.players_requested();
+        ui.apply(ServerMessage::PlayerList { players: vec!["Alice".to_string(), "Bob".to_string()], spectators: vec!["Zoe".to_string()], waiting: vec!["Carol".to_string()] });
+        assert_eq!(ui.events, vec!["Players: Alice, Bob".to_string(), "Spectators: Zoe".to_string(), "Waiting for the next round: Carol".to_string()]);
+    }
+
+    // Test pour le suivi de l'intervalle encore possible
+    #[test]
+    fn test_known_range() {
+        let mut ui = Ui::new(Language::English, "Alice".to_string());
+        ui.apply(ServerMessage::GameStart(Difficulty::Easy));
+        ui.guess_sent(50);
+        ui.apply(ServerMessage::Hint(vec![Hint::Higher]));
+        ui.apply(ServerMessage::GuessWithHint(80, "Bob".to_string(), vec![Hint::Lower]));
+        assert_eq!(ui.range, Some(KnownRange { low: 51, high: 79 }));
+        ui.events.clear();
+        ui.guess_sent(20);
+        ui.suggest();
+        assert_eq!(ui.events, vec![
+            "Warning: 20 is outside the possible range 51 – 79".to_string(),
+            "Optimal guess: 65 (possible range 51 – 79)".to_string(),
+        ]);
+        ui.apply(ServerMessage::GameEnd);
+        assert_eq!(ui.range, None);
+    }
+
+    // Test pour le salon d'attente puis le début de la phase de vote
+    #[test]
+    fn test_lobby() {
+        let mut ui = Ui::new(Language::English, "Alice".to_string());
+        ui.apply(ServerMessage::PlayerList { players: vec!["Alice".to_string(), "Bob".to_string()], spectators: vec![], waiting: vec![] });
+        ui.apply(ServerMessage::Lobby { host: Some("Alice".to_string()), ready: vec!["Bob".to_string()], min_players: 2, countdown: None });
+        let lines = ui.lines(80, 20);
+        assert!(lines[1].starts_with("*Alice ★ "));
+        assert!(lines[2].starts_with(" Bob ✓ "));
+        ui.apply(ServerMessage::Lobby { host: Some("Alice".to_string()), ready: vec!["Alice".to_string(), "Bob".to_string()], min_players: 2, countdown: Some(10) });
+        assert_eq!(ui.timer_text(), Some("Start: 10s".to_string()));
+        ui.apply(ServerMessage::VotingCountdown(20));
+        ui.apply(ServerMessage::VotingCountdown(19));
+        assert!(!ui.in_lobby);
+        assert_eq!(ui.events.len(), 2); // L'invitation du salon, puis celle du vote.
+        assert!(ui.events[1].starts_with("Enter your vote"));
+    }
+
+    // Test pour l'arrivée en cours de manche
+    #[test]
+    fn test_game_state() {
+        let mut ui = Ui::new(Language::English, "Alice".to_string());
+        ui.apply(ServerMessage::GameState { phase: GamePhase::Playing, difficulty: Some(Difficulty::Medium), round: 2, rounds: 3, remaining: Some(30) });
+        assert_eq!(ui.range, Some(KnownRange::new(500)));
+        assert!(ui.timer_text().is_some());
+        assert_eq!(ui.events, vec!["Round 2/3 in progress (difficulty: medium)".to_string()]);
+    }
+
+    // Test pour l'affichage du chat et des messages privés
+    #[test]
+    fn test_chat() {
+        let mut ui = Ui::new(Language::English, "Alice".to_string());
+        ui.apply(ServerMessage::ChatMessage { from: "Bob".to_string(), text: "hi".to_string(), timestamp: 45_000 });
+        ui.apply(ServerMessage::Whisper { from: "Alice".to_string(), to: "Bob".to_string(), text: "psst".to_string(), timestamp: 45_060 });
+        ui.apply(ServerMessage::ChatRejected(ChatError::TooLong(200)));
+        assert_eq!(ui.events, vec![
+            "[12:30] Bob: hi".to_string(),
+            "[12:31] (private to Bob): psst".to_string(),
+            "Message too long (at most 200 characters)".to_string(),
+        ]);
+    }
+
+    // Test pour l'affichage des commandes d'administration
+    #[test]
+    fn test_admin_actions() {
+        let mut ui = Ui::new(Language::English, "Bob".to_string());
+        ui.apply(ServerMessage::AdminAction { by: Some("Alice".to_string()), action: AdminCommand::Kick("Carol".to_string()) });
+        ui.apply(ServerMessage::AdminAction { by: None, action: AdminCommand::TransferHost("Bob".to_string()) });
+        ui.apply(ServerMessage::AdminRejected(AdminError::WrongPhase));
+        ui.apply(ServerMessage::AdminAction { by: Some("Bob".to_string()), action: AdminCommand::SetDifficulty(Difficulty::Hard) });
+        ui.apply(ServerMessage::AdminAction { by: None, action: AdminCommand::Kick("Bob".to_string()) });
+        assert_eq!(ui.host.as_deref(), Some("Bob"));
+        assert_eq!(ui.events, vec![
+            "Carol was kicked by Alice".to_string(),
+            "Bob is now the host (chosen by the server)".to_string(),
+            "This command is not available right now.".to_string(),
+            "Difficulty hard set by Bob for the next round".to_string(),
+            "You were kicked from the game by the server.".to_string(),
+        ]);
+    }
+
+    // Test pour la composition de l'écran
+    #[test]
+    fn test_screen_layout() {
+        let mut ui = Ui::new(Language::English, "Alice".to_string());
+        ui.apply(ServerMessage::PlayerList { players: vec!["Alice".to_string(), "Bob".to_string()], spectators: vec!["Zoe".to_string()], waiting: vec![] });
+        ui.input = "42".to_string();
+        let lines = ui.lines(80, 20);
+        assert_eq!(lines.len(), 20);
+        assert!(lines[..19].iter().all(|line| line.chars().count() == 80));
+        assert!(lines[1].starts_with("*Alice"));
+        assert!(lines[2].starts_with(" Bob"));
+        assert!(lines[4].starts_with("─ Spectators ─"));
+        assert!(lines[5].starts_with(" Zoe"));
+        assert!(lines[19].starts_with("> 42"));
+        assert!(lines[19].ends_with("Esc: quit"));
+    }
+
+    // Test pour la position du curseur, mesurée en colonnes de l'écran
+    #[test]
+    fn test_cursor_column() {
+        let mut ui = Ui::new(Language::English, "Alice".to_string());
+        ui.input = "42".to_string();
+        assert_eq!(ui.cursor_column(80), 4);
+        ui.input = "日本".to_string(); // Caractères larges : deux colonnes chacun
+        assert_eq!(ui.cursor_column(80), 6);
+        ui.input = "e\u{301}te\u{301}".to_string(); // Accents combinés : aucune colonne
+        assert_eq!(ui.cursor_column(80), 5);
+
+        // Un texte plus long que la ligne : seule sa fin est affichée, et le curseur la suit.
+        ui.input = "日".repeat(50);
+        let line = ui.input_line(40);
+        assert_eq!(line.width(), 40);
+        assert!(line.ends_with("Esc: quit"));
+        assert_eq!(ui.cursor_column(40), ui.prompt(40).width());
+        assert_eq!(ui.cursor_column(40), 14); // 40 - 24 (rappel) - 3 = 13 colonnes, soit 6 caractères larges après "> "
+    }
+}
@@ -0,0 +1,104 @@
+use std::io::{self, ErrorKind, Read, Write};
+use bincode::Options;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+// Les messages sont échangés sous forme de trames : la longueur du message sur 4 octets (gros-boutiste),
+// suivie du message sérialisé avec bincode. Contrairement à un délimiteur, la longueur permet au message
+// de contenir n'importe quel octet.
+
+// Taille maximale du contenu d'une trame. Une trame annonçant une longueur plus grande est refusée avant
+// toute allocation, et bincode ne lit jamais plus que cette taille, quelles que soient les longueurs
+// annoncées à l'intérieur du message.
+pub const MAX_FRAME_LEN: u32 = 64 * 1024;
+
+// Options de bincode utilisées pour tous les messages : entiers de taille fixe (comme `bincode::serialize`),
+// taille limitée, et aucun octet superflu accepté après le message.
+fn options() -> impl Options {
+    bincode::DefaultOptions::new().with_fixint_encoding().with_limit(MAX_FRAME_LEN as u64)
+}
+
+// Sérialise un message et l'écrit sous forme d'une trame.
+// La trame est écrite d'un seul bloc pour ne pas être entremêlée avec celle d'un autre thread.
+pub fn write_message<T: Serialize>(writer: &mut impl Write, message: &T) -> io::Result<()> {
+    let encoded = options().serialize(message).map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+    let mut frame = Vec::with_capacity(encoded.len() + 4);
+    frame.extend_from_slice(&(encoded.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&encoded);
+    writer.write_all(&frame)?;
+    writer.flush()
+}
+
+// Lit une trame et renvoie son contenu, ou None si la connexion a été fermée entre deux trames.
+pub fn read_frame(reader: &mut impl Read) -> io::Result<Option<Vec<u8>>> {
+    let mut length = [0; 4];
+    match reader.read_exact(&mut length) {
+        Ok(()) => {},
+        Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error),
+    }
+    let length = u32::from_be_bytes(length);
+    if length > MAX_FRAME_LEN {
+        return Err(io::Error::new(ErrorKind::InvalidData, format!("frame too large: {} bytes", length)));
+    }
+    let mut payload = vec![0; length as usize];
+    reader.read_exact(&mut payload)?;
+    Ok(Some(payload))
+}
+
+// Désérialise le contenu d'une trame.
+pub fn decode<T: DeserializeOwned>(payload: &[u8]) -> bincode::Result<T> {
+    options().deserialize(payload)
+}
+
+// Lit une trame et désérialise le message qu'elle contient.
+// Renvoie None si la connexion a été fermée, et une erreur `InvalidData` si le message est invalide.
+pub fn read_message<T: DeserializeOwned>(reader: &mut impl Read) -> io::Result<Option<T>> {
+    match read_frame(reader)? {
+        Some(payload) => decode(&payload).map(Some).map_err(|error| io::Error::new(ErrorKind::InvalidData, error)),
+        None => Ok(None),
+    }
+}
+
+// Tests unitaires pour le module codec
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::{ClientMessage, ServerMessage};
+
+    // Test pour l'aller-retour d'un message contenant l'octet '\n', qui servait de délimiteur auparavant
+    #[test]
+    fn test_round_trip_with_newline_byte() {
+        let mut buffer = vec![];
+        write_message(&mut buffer, &ClientMessage::Guess(10)).unwrap(); // 10 = '\n'
+        write_message(&mut buffer, &ServerMessage::Notice("a\nb".to_string())).unwrap();
+        let mut reader = buffer.as_slice();
+        assert!(matches!(read_message(&mut reader).unwrap(), Some(ClientMessage::Guess(10))));
+        assert!(matches!(read_message::<ServerMessage>(&mut reader).unwrap(), Some(ServerMessage::Notice(text)) if text == "a\nb"));
+        assert!(read_message::<ServerMessage>(&mut reader).unwrap().is_none());
+    }
+
+    // Test pour les trames tronquées et les messages invalides
+    #[test]
+    fn test_truncated_and_invalid_frames() {
+        let mut reader: &[u8] = &[0, 0, 0, 8, 1, 2];
+        assert_eq!(read_frame(&mut reader).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+        let mut reader: &[u8] = &[0, 0, 0, 1, 255];
+        assert_eq!(read_message::<ClientMessage>(&mut reader).unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    // Test pour les limites de taille : trame trop grande, longueur de chaîne démesurée, message trop grand à envoyer
+    #[test]
+    fn test_size_limits() {
+        let mut reader: &[u8] = &[0xFF, 0xFF, 0xFF, 0xFF];
+        assert_eq!(read_frame(&mut reader).unwrap_err().kind(), ErrorKind::InvalidData);
+
+        // `Notice` (variant 19) annonçant une chaîne de 2^60 octets.
+        let mut payload = 19u32.to_le_bytes().to_vec();
+        payload.extend_from_slice(&(1u64 << 60).to_le_bytes());
+        assert!(decode::<ServerMessage>(&payload).is_err());
+
+        let notice = ServerMessage::Notice("x".repeat(MAX_FRAME_LEN as usize));
+        assert_eq!(write_message(&mut vec![], &notice).unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+}
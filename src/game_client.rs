@@ -0,0 +1,190 @@
+use std::io;
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use crossbeam::channel::{unbounded, Receiver, RecvTimeoutError};
+use crate::codec::{decode, read_frame, write_message};
+use crate::protocol::{AdminCommand, ClientMessage, Difficulty, Language, ServerMessage};
+use crate::transport::Stream;
+use rustls::ClientConfig;
+
+// `GameClient` est une connexion à un serveur de jeu, utilisable par les interfaces, les bots et les tests.
+// Les commandes sont envoyées directement sur la connexion ; les messages du serveur sont lus par un thread
+// dédié et transmis, dans l'ordre, sur un canal (le flux d'événements).
+pub struct GameClient {
+    stream: Stream, // Connexion au serveur (en clair ou chiffrée), utilisée pour l'envoi des commandes.
+    events: Receiver<ServerMessage>, // Messages reçus du serveur.
+    reader: Option<JoinHandle<()>>, // Thread de lecture des messages du serveur.
+}
+
+impl GameClient {
+    // Se connecte au serveur et démarre la lecture de ses messages.
+    pub fn connect(address: impl ToSocketAddrs) -> io::Result<GameClient> {
+        let stream = TcpStream::connect(address)?;
+        stream.set_nodelay(true)?; // Les messages sont courts : on les envoie sans attendre.
+        GameClient::start(Stream::plain(stream))
+    }
+
+    // Se connecte au serveur avec TLS, en vérifiant son certificat pour le nom donné (nom d'hôte ou adresse IP),
+    // puis démarre la lecture de ses messages. Un certificat refusé est signalé par une erreur `InvalidData`.
+    pub fn connect_tls(address: impl ToSocketAddrs, server_name: &str, config: Arc<ClientConfig>) -> io::Result<GameClient> {
+        let stream = TcpStream::connect(address)?;
+        stream.set_nodelay(true)?;
+        let stream = Stream::client(stream, config, server_name)?;
+        stream.handshake()?;
+        GameClient::start(stream)
+    }
+
+    // Démarre la lecture des messages du serveur sur une connexion ouverte.
+    fn start(stream: Stream) -> io::Result<GameClient> {
+        let mut reader = stream.try_clone()?;
+        let (sender, events) = unbounded();
+        let reader = thread::spawn(move || {
+            // La lecture s'arrête quand le serveur ferme la connexion, ou quand plus personne n'écoute les événements.
+            while let Ok(Some(payload)) = read_frame(&mut reader) {
+                match decode(&payload) {
+                    Ok(message) => {
+                        if sender.send(message).is_err() {
+                            break;
+                        }
+                    },
+                    Err(_) => eprintln!("Failed to deserialize"), // Un message invalide est ignoré.
+                }
+            }
+        });
+        Ok(GameClient { stream, events, reader: Some(reader) })
+    }
+
+    // Envoie un message quelconque au serveur.
+    pub fn send(&self, message: &ClientMessage) -> io::Result<()> {
+        write_message(&mut &self.stream, message)
+    }
+
+    // Rejoint la partie avec le nom et la langue donnés.
+    pub fn join(&self, name: &str, language: Language) -> io::Result<()> {
+        self.send(&ClientMessage::Join { name: name.to_string(), language })
+    }
+
+    // Se connecte à un compte avant de rejoindre la partie (réponse : `Authenticated` ou `AuthRejected`).
+    pub fn login(&self, name: &str, password: &str) -> io::Result<()> {
+        self.send(&ClientMessage::Login { name: name.to_string(), password: password.to_string() })
+    }
+
+    // Crée un compte, et s'y connecte, avant de rejoindre la partie (réponse : `Authenticated` ou `AuthRejected`).
+    pub fn register(&self, name: &str, password: &str) -> io::Result<()> {
+        self.send(&ClientMessage::Register { name: name.to_string(), password: password.to_string() })
+    }
+
+    // Regarde la partie en spectateur, avec le nom et la langue donnés.
+    pub fn join_as_spectator(&self, name: &str, language: Language) -> io::Result<()> {
+        self.send(&ClientMessage::JoinAsSpectator { name: name.to_string(), language })
+    }
+
+    // Vote pour la difficulté de la prochaine manche.
+    pub fn vote(&self, difficulty: Difficulty) -> io::Result<()> {
+        self.send(&ClientMessage::DifficultyVote(difficulty))
+    }
+
+    // Propose un nombre.
+    pub fn guess(&self, guess: u32) -> io::Result<()> {
+        self.send(&ClientMessage::Guess(guess))
+    }
+
+    // Demande la liste des joueurs (reçue sous forme de `ServerMessage::PlayerList`).
+    pub fn request_players(&self) -> io::Result<()> {
+        self.send(&ClientMessage::RequestPlayers)
+    }
+
+    // Demande le classement du match (reçu sous forme de `ServerMessage::Leaderboard`).
+    pub fn request_leaderboard(&self) -> io::Result<()> {
+        self.send(&ClientMessage::RequestLeaderboard)
+    }
+
+    // Dans le salon d'attente, se déclare prêt (ou ne l'est plus).
+    pub fn ready(&self) -> io::Result<()> {
+        self.send(&ClientMessage::Ready)
+    }
+
+    // Dans le salon d'attente, lance le match (réservé à l'hôte).
+    pub fn start_match(&self) -> io::Result<()> {
+        self.send(&ClientMessage::StartMatch)
+    }
+
+    // Envoie une commande d'administration (réservée à l'hôte).
+    pub fn admin(&self, command: AdminCommand) -> io::Result<()> {
+        self.send(&ClientMessage::Admin(command))
+    }
+
+    // Envoie un message à tous les joueurs.
+    pub fn chat(&self, text: &str) -> io::Result<()> {
+        self.send(&ClientMessage::Chat(text.to_string()))
+    }
+
+    // Envoie un message privé à un joueur.
+    pub fn whisper(&self, to: &str, text: &str) -> io::Result<()> {
+        self.send(&ClientMessage::Whisper { to: to.to_string(), text: text.to_string() })
+    }
+
+    // Renvoie le flux des messages reçus du serveur. Le canal peut être cloné pour être lu depuis un autre thread ;
+    // il est fermé quand la connexion se termine.
+    pub fn events(&self) -> &Receiver<ServerMessage> {
+        &self.events
+    }
+
+    // Attend le prochain message du serveur pendant au plus `timeout`.
+    // Renvoie None si aucun message n'est arrivé à temps ou si la connexion est fermée.
+    pub fn next_event(&self, timeout: Duration) -> Option<ServerMessage> {
+        match self.events.recv_timeout(timeout) {
+            Ok(message) => Some(message),
+            Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => None,
+        }
+    }
+
+    // Ferme la connexion et attend la fin du thread de lecture.
+    pub fn close(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stream.shutdown(Shutdown::Both).ok(); // Débloque le thread de lecture.
+        if let Some(reader) = self.reader.take() {
+            reader.join().ok();
+        }
+    }
+}
+
+impl Drop for GameClient {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+// Tests unitaires pour le module game_client
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use crate::codec::read_message;
+
+    // Test des commandes et du flux d'événements avec un faux serveur
+    #[test]
+    fn test_commands_and_events() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = GameClient::connect(listener.local_addr().unwrap()).unwrap();
+        let (mut server, _) = listener.accept().unwrap();
+
+        client.join("Alice", Language::French).unwrap();
+        client.guess(10).unwrap();
+        assert!(matches!(read_message(&mut server).unwrap(), Some(ClientMessage::Join { name, language: Language::French }) if name == "Alice"));
+        assert!(matches!(read_message(&mut server).unwrap(), Some(ClientMessage::Guess(10))));
+
+        write_message(&mut server, &ServerMessage::PlayerList { players: vec!["Alice".to_string()], spectators: vec![], waiting: vec![] }).unwrap();
+        let event = client.next_event(Duration::from_secs(5));
+        assert!(matches!(event, Some(ServerMessage::PlayerList { players, .. }) if players == ["Alice"]));
+
+        drop(server); // Le serveur ferme la connexion : le flux d'événements se termine.
+        assert!(client.events().recv().is_err());
+        client.close();
+    }
+}
@@ -0,0 +1,582 @@
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+use serde::{Serialize, Deserialize};
+use crate::protocol::{AuthError, Difficulty, Hint, Temperature};
+
+// Enumération représentant les langues disponibles pour les textes affichés aux joueurs.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum Language {
+    French,
+    English,
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    // Permet de lire une langue depuis un code ("fr", "en") ou une locale ("fr_FR.UTF-8").
+    fn from_str(value: &str) -> Result<Language, String> {
+        let code = value.trim().to_lowercase();
+        if code.starts_with("fr") {
+            Ok(Language::French)
+        } else if code.starts_with("en") {
+            Ok(Language::English)
+        } else {
+            Err(format!("unsupported language: {}", value))
+        }
+    }
+}
+
+impl Language {
+    // Détermine la langue préférée de l'utilisateur : NUMBER_GAME_LANG, puis la locale du système (LANG), puis l'anglais.
+    pub fn from_env() -> Language {
+        ["NUMBER_GAME_LANG", "LANG"].iter()
+            .filter_map(|name| env::var(name).ok())
+            .find_map(|value| value.parse().ok())
+            .unwrap_or(Language::English)
+    }
+}
+
+// Identifiants des messages affichés aux joueurs. Chaque identifiant a un texte dans chaque catalogue.
+// Les `{}` des textes sont remplacés, dans l'ordre, par les arguments passés à `tr`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MessageId {
+    ConnectionFailed,
+    EnterName,
+    Welcome,
+    VotePrompt,
+    VotingClosed,
+    InvalidInput,
+    GameStarted,
+    TimerStarted,
+    HintReceived,
+    PlayerGuessed,
+    PlayerGuessedWithHint,
+    RoundWon,
+    RoundEnded,
+    RoundTimeout,
+    RaceProgress,
+    RaceTimeout,
+    RaceSecret,
+    YourTurn,
+    OtherTurn,
+    TurnSkipped,
+    GuessWrongPhase,
+    GuessNotYourTurn,
+    StandingsHeader,
+    StandingsLine,
+    NextRoundVote,
+    MatchWon,
+    MatchDraw,
+    NewMatch,
+    DifficultyEasy,
+    DifficultyMedium,
+    DifficultyHard,
+    HintHigher,
+    HintLower,
+    HintCorrect,
+    HintBurning,
+    HintHot,
+    HintWarm,
+    HintCold,
+    HintFreezing,
+    HintEven,
+    HintOdd,
+    HintDivisible,
+    HintNotDivisible,
+    HintDigitSum,
+    HintWithin,
+    HintNotWithin,
+    PanePlayers,
+    PaneVotes,
+    PaneTimer,
+    PaneGuesses,
+    PaneEvents,
+    VotingTimer,
+    RoundTimer,
+    GuessHistoryLine,
+    QuitHint,
+    UnknownCommand,
+    UsageError,
+    UnknownLevel,
+    PlayersLine,
+    LeaderboardHeader,
+    HelpHeader,
+    HelpGuess,
+    HelpPlayers,
+    HelpVote,
+    HelpLeaderboard,
+    HelpChat,
+    HelpReady,
+    HelpQuit,
+    HelpHelp,
+    PaneRange,
+    GuessOutsideRange,
+    SuggestedGuess,
+    NoRange,
+    HelpSuggest,
+    HelpWhisper,
+    ChatLine,
+    WhisperReceived,
+    WhisperSent,
+    ChatEmpty,
+    ChatTooLong,
+    ChatRateLimited,
+    ChatBlocked,
+    ChatUnknownRecipient,
+    SpectatorCannotVote,
+    GuessSpectating,
+    PaneSpectators,
+    SpectatorsLine,
+    PaneWaiting,
+    WaitingLine,
+    SecretRevealed,
+    Spectating,
+    GuessNextRound,
+    LateJoinQueued,
+    LateJoinSpectating,
+    RoundInProgress,
+    VotingInProgress,
+    HelpStart,
+    NotInLobby,
+    NotHost,
+    NotEnoughPlayers,
+    LobbySpectating,
+    LobbyPrompt,
+    LobbyTimer,
+    Banned,
+    Kicked,
+    ServerName,
+    AdminNotHost,
+    AdminWrongPhase,
+    AdminUnknownPlayer,
+    AdminOwnPlayer,
+    AdminNoPlayers,
+    ActionKick,
+    ActionBan,
+    ActionForceStart,
+    ActionSkipVoting,
+    ActionEndRound,
+    ActionDifficulty,
+    ActionHost,
+    HelpKick,
+    HelpBan,
+    HelpForceStart,
+    HelpSkipVote,
+    HelpEndRound,
+    HelpDifficulty,
+    HelpHost,
+    Announcement,
+    ServerShutdown,
+    ServerShutdownReason,
+    EnterPassword,
+    LoggedIn,
+    AuthWrongCredentials,
+    AuthNameTaken,
+    AuthNameReserved,
+    AuthGuestsNotAllowed,
+    AuthInvalidName,
+    AuthWeakPassword,
+    AuthUnavailable,
+    AuthNameInUse,
+    AuthTooManyAttempts,
+    Disconnected,
+}
+
+// Catalogue des textes en français.
+fn french(id: MessageId) -> &'static str {
+    match id {
+        MessageId::ConnectionFailed => "Impossible de se connecter au serveur",
+        MessageId::EnterName => "Entrez votre nom :",
+        MessageId::Welcome => "Bienvenue {} !",
+        MessageId::VotePrompt => "À vous de voter ! Choisissez la difficulté entre 'facile', 'moyen' et 'difficile' :",
+        MessageId::VotingClosed => "La phase de vote est terminée. Veuillez attendre la prochaine partie.",
+        MessageId::InvalidInput => "Entrée invalide (tapez /help)",
+        MessageId::GameStarted => "La partie commence en difficulté {}. Bon jeu :) Entrez votre première proposition :",
+        MessageId::TimerStarted => "Vous avez {} secondes pour trouver le nombre !",
+        MessageId::HintReceived => "Indice : {}",
+        MessageId::PlayerGuessed => "{} a proposé {}",
+        MessageId::PlayerGuessedWithHint => "{} a proposé {} : {}",
+        MessageId::RoundWon => "{} a gagné la manche !",
+        MessageId::RoundEnded => "Manche terminée",
+        MessageId::RoundTimeout => "Temps écoulé ! Le nombre secret était {}",
+        MessageId::RaceProgress => "{} en est à {} tentative(s)",
+        MessageId::RaceTimeout => "Temps écoulé ! Les nombres secrets étaient :",
+        MessageId::RaceSecret => "  {} : {}",
+        MessageId::YourTurn => "C’est votre tour ! Entrez votre proposition :",
+        MessageId::OtherTurn => "C’est au tour de {}.",
+        MessageId::TurnSkipped => "{} n’a pas joué à temps et passe son tour.",
+        MessageId::GuessWrongPhase => "Vous ne pouvez pas deviner maintenant. Veuillez attendre le début de la partie.",
+        MessageId::GuessNotYourTurn => "Ce n’est pas votre tour, veuillez patienter.",
+        MessageId::StandingsHeader => "Classement après la manche {}/{} :",
+        MessageId::StandingsLine => "  {}. {} - {} point(s)",
+        MessageId::NextRoundVote => "Votez pour la difficulté de la prochaine manche : 'facile', 'moyen' ou 'difficile'",
+        MessageId::MatchWon => "Match terminé ! {} remporte le match !",
+        MessageId::MatchDraw => "Match terminé ! Égalité.",
+        MessageId::NewMatch => "Un nouveau match commence, votez pour la difficulté : 'facile', 'moyen' ou 'difficile'",
+        MessageId::DifficultyEasy => "facile",
+        MessageId::DifficultyMedium => "moyen",
+        MessageId::DifficultyHard => "difficile",
+        MessageId::HintHigher => "C’est plus",
+        MessageId::HintLower => "C’est moins",
+        MessageId::HintCorrect => "Vous avez gagné!",
+        MessageId::HintBurning => "Ça brûle !",
+        MessageId::HintHot => "C’est chaud",
+        MessageId::HintWarm => "C’est tiède",
+        MessageId::HintCold => "C’est froid",
+        MessageId::HintFreezing => "C’est glacial",
+        MessageId::HintEven => "Le nombre est pair",
+        MessageId::HintOdd => "Le nombre est impair",
+        MessageId::HintDivisible => "Le nombre est divisible par {}",
+        MessageId::HintNotDivisible => "Le nombre n’est pas divisible par {}",
+        MessageId::HintDigitSum => "La somme des chiffres du nombre est {}",
+        MessageId::HintWithin => "Vous êtes à {} ou moins du nombre",
+        MessageId::HintNotWithin => "Vous êtes à plus de {} du nombre",
+        MessageId::PanePlayers => "Joueurs",
+        MessageId::PaneVotes => "Votes",
+        MessageId::PaneTimer => "Minuteur",
+        MessageId::PaneGuesses => "Vos propositions",
+        MessageId::PaneEvents => "Événements",
+        MessageId::VotingTimer => "Vote : {} s",
+        MessageId::RoundTimer => "Manche : {} s",
+        MessageId::GuessHistoryLine => "{} → {}",
+        MessageId::QuitHint => "Tab : compléter, Échap : quitter",
+        MessageId::UnknownCommand => "Commande inconnue : {} (tapez /help)",
+        MessageId::UsageError => "Usage : {}",
+        MessageId::UnknownLevel => "Difficulté inconnue : {}",
+        MessageId::PlayersLine => "Joueurs : {}",
+        MessageId::LeaderboardHeader => "Classement du match :",
+        MessageId::HelpHeader => "Commandes disponibles :",
+        MessageId::HelpGuess => "  <nombre> : proposer un nombre",
+        MessageId::HelpPlayers => "  {} : afficher la liste des joueurs",
+        MessageId::HelpVote => "  {} : voter pour la difficulté",
+        MessageId::HelpLeaderboard => "  {} : afficher le classement du match",
+        MessageId::HelpChat => "  {} : envoyer un message aux autres joueurs",
+        MessageId::HelpReady => "  {} : se déclarer prêt (ou ne plus l’être) dans le salon",
+        MessageId::HelpStart => "  {} : lancer le match (hôte uniquement)",
+        MessageId::HelpQuit => "  {} : quitter le jeu",
+        MessageId::HelpHelp => "  {} : afficher cette aide",
+        MessageId::PaneRange => "Intervalle",
+        MessageId::GuessOutsideRange => "Attention : {} est hors de l’intervalle possible {} – {}",
+        MessageId::SuggestedGuess => "Proposition optimale : {} (intervalle possible {} – {})",
+        MessageId::NoRange => "Aucune manche en cours",
+        MessageId::HelpSuggest => "  {} : afficher la proposition optimale (entraînement)",
+        MessageId::HelpWhisper => "  {} : envoyer un message privé à un joueur",
+        MessageId::ChatLine => "[{}] {} : {}",
+        MessageId::WhisperReceived => "[{}] {} (privé) : {}",
+        MessageId::WhisperSent => "[{}] (privé à {}) : {}",
+        MessageId::ChatEmpty => "Le message est vide",
+        MessageId::ChatTooLong => "Message trop long ({} caractères au plus)",
+        MessageId::ChatRateLimited => "Trop de messages, patientez un peu",
+        MessageId::ChatBlocked => "Message refusé : il contient un mot interdit",
+        MessageId::ChatUnknownRecipient => "Ce joueur n’est pas connecté",
+        MessageId::SpectatorCannotVote => "Les spectateurs ne peuvent pas voter.",
+        MessageId::GuessSpectating => "Les spectateurs ne peuvent pas deviner.",
+        MessageId::PaneSpectators => "Spectateurs",
+        MessageId::SpectatorsLine => "Spectateurs : {}",
+        MessageId::PaneWaiting => "Manche suivante",
+        MessageId::WaitingLine => "En attente de la manche suivante : {}",
+        MessageId::SecretRevealed => "Le nombre secret était {}",
+        MessageId::Spectating => "Vous regardez la partie en spectateur.",
+        MessageId::GuessNextRound => "Vous jouerez à partir de la manche suivante, veuillez patienter.",
+        MessageId::LateJoinQueued => "Une manche est en cours : vous rejoindrez la partie à la manche suivante.",
+        MessageId::LateJoinSpectating => "Une manche est en cours : vous la regardez en spectateur et jouerez à partir de la manche suivante.",
+        MessageId::RoundInProgress => "Manche {}/{} en cours (difficulté : {})",
+        MessageId::VotingInProgress => "Vote en cours pour la manche {}/{}",
+        MessageId::NotInLobby => "Le match a déjà commencé.",
+        MessageId::NotHost => "Seul l’hôte peut lancer le match.",
+        MessageId::NotEnoughPlayers => "Il n’y a pas assez de joueurs pour lancer le match.",
+        MessageId::LobbySpectating => "Les spectateurs ne participent pas au salon.",
+        MessageId::LobbyPrompt => "Salon d’attente : tapez /ready quand vous êtes prêt. Le match commence quand tout le monde est prêt (au moins {} joueurs).",
+        MessageId::LobbyTimer => "Début : {} s",
+        MessageId::Banned => "Vous êtes exclu de cette partie.",
+        MessageId::Kicked => "Vous avez été exclu de la partie par {}.",
+        MessageId::ServerName => "le serveur",
+        MessageId::AdminNotHost => "Seul l’hôte peut administrer la partie.",
+        MessageId::AdminWrongPhase => "Cette commande n’est pas disponible pour le moment.",
+        MessageId::AdminUnknownPlayer => "Ce joueur n’est pas dans la partie.",
+        MessageId::AdminOwnPlayer => "Vous ne pouvez pas vous exclure vous-même.",
+        MessageId::AdminNoPlayers => "Il n’y a aucun joueur pour lancer le match.",
+        MessageId::ActionKick => "{} a été exclu par {}",
+        MessageId::ActionBan => "{} a été banni par {}",
+        MessageId::ActionForceStart => "Le match a été lancé par {}",
+        MessageId::ActionSkipVoting => "La phase de vote a été terminée par {}",
+        MessageId::ActionEndRound => "La manche a été terminée par {}",
+        MessageId::ActionDifficulty => "Difficulté {} imposée par {} pour la prochaine manche",
+        MessageId::ActionHost => "{} est maintenant l’hôte (choisi par {})",
+        MessageId::HelpKick => "  {} : exclure un joueur (hôte)",
+        MessageId::HelpBan => "  {} : exclure un joueur ou une adresse IP jusqu’à l’arrêt du serveur (hôte)",
+        MessageId::HelpForceStart => "  {} : lancer le match sans attendre que tout le monde soit prêt (hôte)",
+        MessageId::HelpSkipVote => "  {} : terminer la phase de vote immédiatement (hôte)",
+        MessageId::HelpEndRound => "  {} : terminer la manche en cours (hôte)",
+        MessageId::HelpDifficulty => "  {} : imposer la difficulté de la prochaine manche (hôte)",
+        MessageId::HelpHost => "  {} : donner le rôle d’hôte à un autre joueur (hôte)",
+        MessageId::Announcement => "[Serveur] {}",
+        MessageId::ServerShutdown => "Le serveur s’arrête dans {} s.",
+        MessageId::ServerShutdownReason => "Le serveur s’arrête dans {} s : {}",
+        MessageId::EnterPassword => "Mot de passe : ",
+        MessageId::LoggedIn => "Connecté au compte {}.",
+        MessageId::AuthWrongCredentials => "Nom ou mot de passe incorrect.",
+        MessageId::AuthNameTaken => "Ce nom est déjà utilisé par un compte.",
+        MessageId::AuthNameReserved => "Ce nom appartient à un compte : connectez-vous avec --login pour l’utiliser.",
+        MessageId::AuthGuestsNotAllowed => "Ce serveur n’accepte que les joueurs ayant un compte (--login ou --register).",
+        MessageId::AuthInvalidName => "Ce nom ne peut pas être utilisé pour un compte.",
+        MessageId::AuthWeakPassword => "Le mot de passe doit contenir au moins 8 caractères.",
+        MessageId::AuthUnavailable => "Les comptes sont indisponibles sur ce serveur.",
+        MessageId::AuthNameInUse => "Ce nom est déjà utilisé par un joueur connecté.",
+        MessageId::AuthTooManyAttempts => "Trop de tentatives ont échoué : le serveur a fermé la connexion.",
+        MessageId::Disconnected => "La connexion au serveur a été fermée.",
+    }
+}
+
+// Catalogue des textes en anglais.
+fn english(id: MessageId) -> &'static str {
+    match id {
+        MessageId::ConnectionFailed => "Could not connect to server",
+        MessageId::EnterName => "Enter your name:",
+        MessageId::Welcome => "Welcome {}!",
+        MessageId::VotePrompt => "Enter your vote!! Choose between 'easy', 'medium', 'hard' to vote for difficulty:",
+        MessageId::VotingClosed => "Voting phase is over. Please wait for the next game.",
+        MessageId::InvalidInput => "Invalid input (type /help)",
+        MessageId::GameStarted => "Game started with difficulty: {}. Enjoy the game :) and enter your first guess:",
+        MessageId::TimerStarted => "You have {} seconds to find the number!",
+        MessageId::HintReceived => "Hint: {}",
+        MessageId::PlayerGuessed => "{} guessed {}",
+        MessageId::PlayerGuessedWithHint => "{} guessed {}: {}",
+        MessageId::RoundWon => "{} has won the round!",
+        MessageId::RoundEnded => "Round ended",
+        MessageId::RoundTimeout => "Time's up! The secret number was {}",
+        MessageId::RaceProgress => "{} has made {} attempt(s)",
+        MessageId::RaceTimeout => "Time's up! The secret numbers were:",
+        MessageId::RaceSecret => "  {}: {}",
+        MessageId::YourTurn => "It's your turn! Enter your guess:",
+        MessageId::OtherTurn => "It's {}'s turn.",
+        MessageId::TurnSkipped => "{} ran out of time and skips their turn.",
+        MessageId::GuessWrongPhase => "You cannot guess right now. Please wait for the game to start.",
+        MessageId::GuessNotYourTurn => "It's not your turn, please wait.",
+        MessageId::StandingsHeader => "Standings after round {}/{}:",
+        MessageId::StandingsLine => "  {}. {} - {} point(s)",
+        MessageId::NextRoundVote => "Vote for the next round's difficulty: 'easy', 'medium' or 'hard'",
+        MessageId::MatchWon => "Match over! {} wins the match!",
+        MessageId::MatchDraw => "Match over! It's a draw.",
+        MessageId::NewMatch => "A new match is starting, vote for the difficulty: 'easy', 'medium' or 'hard'",
+        MessageId::DifficultyEasy => "easy",
+        MessageId::DifficultyMedium => "medium",
+        MessageId::DifficultyHard => "hard",
+        MessageId::HintHigher => "Higher",
+        MessageId::HintLower => "Lower",
+        MessageId::HintCorrect => "You won!",
+        MessageId::HintBurning => "Burning!",
+        MessageId::HintHot => "Hot",
+        MessageId::HintWarm => "Warm",
+        MessageId::HintCold => "Cold",
+        MessageId::HintFreezing => "Freezing",
+        MessageId::HintEven => "The number is even",
+        MessageId::HintOdd => "The number is odd",
+        MessageId::HintDivisible => "The number is divisible by {}",
+        MessageId::HintNotDivisible => "The number is not divisible by {}",
+        MessageId::HintDigitSum => "The digits of the number add up to {}",
+        MessageId::HintWithin => "You are within {} of the number",
+        MessageId::HintNotWithin => "You are more than {} away from the number",
+        MessageId::PanePlayers => "Players",
+        MessageId::PaneVotes => "Votes",
+        MessageId::PaneTimer => "Timer",
+        MessageId::PaneGuesses => "Your guesses",
+        MessageId::PaneEvents => "Events",
+        MessageId::VotingTimer => "Voting: {}s",
+        MessageId::RoundTimer => "Round: {}s",
+        MessageId::GuessHistoryLine => "{} → {}",
+        MessageId::QuitHint => "Tab: complete, Esc: quit",
+        MessageId::UnknownCommand => "Unknown command: {} (type /help)",
+        MessageId::UsageError => "Usage: {}",
+        MessageId::UnknownLevel => "Unknown difficulty: {}",
+        MessageId::PlayersLine => "Players: {}",
+        MessageId::LeaderboardHeader => "Match leaderboard:",
+        MessageId::HelpHeader => "Available commands:",
+        MessageId::HelpGuess => "  <number>: guess a number",
+        MessageId::HelpPlayers => "  {}: show the player list",
+        MessageId::HelpVote => "  {}: vote for the difficulty",
+        MessageId::HelpLeaderboard => "  {}: show the match leaderboard",
+        MessageId::HelpChat => "  {}: send a message to the other players",
+        MessageId::HelpReady => "  {}: toggle your ready state in the lobby",
+        MessageId::HelpStart => "  {}: start the match (host only)",
+        MessageId::HelpQuit => "  {}: leave the game",
+        MessageId::HelpHelp => "  {}: show this help",
+        MessageId::PaneRange => "Range",
+        MessageId::GuessOutsideRange => "Warning: {} is outside the possible range {} – {}",
+        MessageId::SuggestedGuess => "Optimal guess: {} (possible range {} – {})",
+        MessageId::NoRange => "No round in progress",
+        MessageId::HelpSuggest => "  {}: show the optimal next guess (training)",
+        MessageId::HelpWhisper => "  {}: send a private message to a player",
+        MessageId::ChatLine => "[{}] {}: {}",
+        MessageId::WhisperReceived => "[{}] {} (private): {}",
+        MessageId::WhisperSent => "[{}] (private to {}): {}",
+        MessageId::ChatEmpty => "The message is empty",
+        MessageId::ChatTooLong => "Message too long (at most {} characters)",
+        MessageId::ChatRateLimited => "Too many messages, please wait a moment",
+        MessageId::ChatBlocked => "Message rejected: it contains a blocked word",
+        MessageId::ChatUnknownRecipient => "That player is not connected",
+        MessageId::SpectatorCannotVote => "Spectators cannot vote.",
+        MessageId::GuessSpectating => "Spectators cannot guess.",
+        MessageId::PaneSpectators => "Spectators",
+        MessageId::SpectatorsLine => "Spectators: {}",
+        MessageId::PaneWaiting => "Next round",
+        MessageId::WaitingLine => "Waiting for the next round: {}",
+        MessageId::SecretRevealed => "The secret number was {}",
+        MessageId::Spectating => "You are watching the game as a spectator.",
+        MessageId::GuessNextRound => "You will play from the next round, please wait.",
+        MessageId::LateJoinQueued => "A round is in progress: you will join the game at the next round.",
+        MessageId::LateJoinSpectating => "A round is in progress: you are watching it as a spectator and will play from the next round.",
+        MessageId::RoundInProgress => "Round {}/{} in progress (difficulty: {})",
+        MessageId::VotingInProgress => "Voting for round {}/{}",
+        MessageId::NotInLobby => "The match has already started.",
+        MessageId::NotHost => "Only the host can start the match.",
+        MessageId::NotEnoughPlayers => "There are not enough players to start the match.",
+        MessageId::LobbySpectating => "Spectators do not take part in the lobby.",
+        MessageId::LobbyPrompt => "Lobby: type /ready when you are ready. The match starts when everyone is ready (at least {} players).",
+        MessageId::LobbyTimer => "Start: {}s",
+        MessageId::Banned => "You are banned from this game.",
+        MessageId::Kicked => "You were kicked from the game by {}.",
+        MessageId::ServerName => "the server",
+        MessageId::AdminNotHost => "Only the host can manage the game.",
+        MessageId::AdminWrongPhase => "This command is not available right now.",
+        MessageId::AdminUnknownPlayer => "That player is not in the game.",
+        MessageId::AdminOwnPlayer => "You cannot kick or ban yourself.",
+        MessageId::AdminNoPlayers => "There are no players to start the match.",
+        MessageId::ActionKick => "{} was kicked by {}",
+        MessageId::ActionBan => "{} was banned by {}",
+        MessageId::ActionForceStart => "The match was started by {}",
+        MessageId::ActionSkipVoting => "The voting phase was ended by {}",
+        MessageId::ActionEndRound => "The round was ended by {}",
+        MessageId::ActionDifficulty => "Difficulty {} set by {} for the next round",
+        MessageId::ActionHost => "{} is now the host (chosen by {})",
+        MessageId::HelpKick => "  {}: kick a player (host)",
+        MessageId::HelpBan => "  {}: ban a player or an IP address until the server stops (host)",
+        MessageId::HelpForceStart => "  {}: start the match without waiting for everyone to be ready (host)",
+        MessageId::HelpSkipVote => "  {}: end the voting phase now (host)",
+        MessageId::HelpEndRound => "  {}: end the current round (host)",
+        MessageId::HelpDifficulty => "  {}: set the difficulty of the next round (host)",
+        MessageId::HelpHost => "  {}: make another player the host (host)",
+        MessageId::Announcement => "[Server] {}",
+        MessageId::ServerShutdown => "The server is shutting down in {}s.",
+        MessageId::ServerShutdownReason => "The server is shutting down in {}s: {}",
+        MessageId::EnterPassword => "Password: ",
+        MessageId::LoggedIn => "Logged in as {}.",
+        MessageId::AuthWrongCredentials => "Wrong name or password.",
+        MessageId::AuthNameTaken => "This name is already used by an account.",
+        MessageId::AuthNameReserved => "This name belongs to an account: log in with --login to use it.",
+        MessageId::AuthGuestsNotAllowed => "This server only accepts players with an account (--login or --register).",
+        MessageId::AuthInvalidName => "This name cannot be used for an account.",
+        MessageId::AuthWeakPassword => "The password must be at least 8 characters long.",
+        MessageId::AuthUnavailable => "Accounts are unavailable on this server.",
+        MessageId::AuthNameInUse => "This name is already used by a connected player.",
+        MessageId::AuthTooManyAttempts => "Too many failed attempts: the server closed the connection.",
+        MessageId::Disconnected => "The connection to the server was closed.",
+    }
+}
+
+// Renvoie le texte d'un message dans la langue donnée, en remplaçant les `{}` par les arguments.
+pub fn tr(language: Language, id: MessageId, args: &[&dyn fmt::Display]) -> String {
+    let template = match language {
+        Language::French => french(id),
+        Language::English => english(id),
+    };
+    let mut parts = template.split("{}");
+    let mut text = parts.next().unwrap_or_default().to_string();
+    let mut args = args.iter();
+    for part in parts {
+        if let Some(arg) = args.next() {
+            text.push_str(&arg.to_string());
+        }
+        text.push_str(part);
+    }
+    text
+}
+
+// Renvoie le nom d'un niveau de difficulté dans la langue donnée.
+pub fn difficulty_text(language: Language, difficulty: Difficulty) -> String {
+    let id = match difficulty {
+        Difficulty::Easy => MessageId::DifficultyEasy,
+        Difficulty::Medium => MessageId::DifficultyMedium,
+        Difficulty::Hard => MessageId::DifficultyHard,
+    };
+    tr(language, id, &[])
+}
+
+// Reconnaît un niveau de difficulté tapé par le joueur, dans n'importe quelle langue disponible.
+pub fn parse_difficulty(input: &str) -> Option<Difficulty> {
+    let input = input.trim().to_lowercase();
+    [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard].into_iter().find(|&difficulty| {
+        [Language::French, Language::English].into_iter().any(|language| difficulty_text(language, difficulty) == input)
+    })
+}
+
+// Renvoie le texte d'un indice dans la langue donnée.
+pub fn hint_text(language: Language, hint: &Hint) -> String {
+    match *hint {
+        Hint::Higher => tr(language, MessageId::HintHigher, &[]),
+        Hint::Lower => tr(language, MessageId::HintLower, &[]),
+        Hint::Correct => tr(language, MessageId::HintCorrect, &[]),
+        Hint::Temperature(Temperature::Burning) => tr(language, MessageId::HintBurning, &[]),
+        Hint::Temperature(Temperature::Hot) => tr(language, MessageId::HintHot, &[]),
+        Hint::Temperature(Temperature::Warm) => tr(language, MessageId::HintWarm, &[]),
+        Hint::Temperature(Temperature::Cold) => tr(language, MessageId::HintCold, &[]),
+        Hint::Temperature(Temperature::Freezing) => tr(language, MessageId::HintFreezing, &[]),
+        Hint::Parity { even: true } => tr(language, MessageId::HintEven, &[]),
+        Hint::Parity { even: false } => tr(language, MessageId::HintOdd, &[]),
+        Hint::Divisible { divisor, divisible: true } => tr(language, MessageId::HintDivisible, &[&divisor]),
+        Hint::Divisible { divisor, divisible: false } => tr(language, MessageId::HintNotDivisible, &[&divisor]),
+        Hint::DigitSum(sum) => tr(language, MessageId::HintDigitSum, &[&sum]),
+        Hint::Within { distance, within: true } => tr(language, MessageId::HintWithin, &[&distance]),
+        Hint::Within { distance, within: false } => tr(language, MessageId::HintNotWithin, &[&distance]),
+    }
+}
+
+// Renvoie le message expliquant le refus d'une connexion, d'une création de compte ou d'une arrivée dans la partie.
+pub fn auth_error_text(language: Language, error: AuthError) -> String {
+    let id = match error {
+        AuthError::WrongCredentials => MessageId::AuthWrongCredentials,
+        AuthError::NameTaken => MessageId::AuthNameTaken,
+        AuthError::NameReserved => MessageId::AuthNameReserved,
+        AuthError::GuestsNotAllowed => MessageId::AuthGuestsNotAllowed,
+        AuthError::InvalidName => MessageId::AuthInvalidName,
+        AuthError::WeakPassword => MessageId::AuthWeakPassword,
+        AuthError::Unavailable => MessageId::AuthUnavailable,
+        AuthError::NameInUse => MessageId::AuthNameInUse,
+        AuthError::TooManyAttempts => MessageId::AuthTooManyAttempts,
+    };
+    tr(language, id, &[])
+}
+
+// Tests unitaires pour le module i18n
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour la substitution des arguments dans les textes
+    #[test]
+    fn test_tr_with_arguments() {
+        assert_eq!(tr(Language::English, MessageId::PlayerGuessed, &[&"Alice", &42]), "Alice guessed 42");
+        assert_eq!(tr(Language::French, MessageId::PlayerGuessed, &[&"Alice", &42]), "Alice a proposé 42");
+        assert_eq!(tr(Language::French, MessageId::RoundEnded, &[]), "Manche terminée");
+    }
+
+    // Test pour la lecture des codes de langue
+    #[test]
+    fn test_parse_language() {
+        assert_eq!("fr".parse(), Ok(Language::French));
+        assert_eq!("fr_FR.UTF-8".parse(), Ok(Language::French));
+        assert_eq!("en_US".parse(), Ok(Language::English));
+        assert!("de".parse::<Language>().is_err());
+    }
+
+    // Test pour les textes des indices et des difficultés
+    #[test]
+    fn test_hint_and_difficulty_text() {
+        assert_eq!(hint_text(Language::French, &Hint::Higher), "C’est plus");
+        assert_eq!(hint_text(Language::English, &Hint::Divisible { divisor: 3, divisible: false }), "The number is not divisible by 3");
+        assert_eq!(parse_difficulty("Difficile"), Some(Difficulty::Hard));
+        assert_eq!(parse_difficulty("medium"), Some(Difficulty::Medium));
+        assert_eq!(parse_difficulty("impossible"), None);
+    }
+}
@@ -0,0 +1,11 @@
+// Bibliothèque du jeu de devinette de nombres : types du protocole, encodage des messages et logique du serveur.
+// Le client fourni, comme tout client ou bot tiers, ne dépend que du protocole, de l'encodage et des textes traduits.
+pub mod bot;
+pub mod codec;
+pub mod game_client;
+pub mod i18n;
+pub mod protocol;
+pub mod range;
+pub mod server;
+pub mod tls;
+pub mod transport;
@@ -1,9 +1,6 @@
-mod game;
-mod player;
-mod util;
-mod server;
+use number_game::server::{self, config::ServerConfig};
 
 fn main() {
     // Le point d'entrée du programme. Le serveur est démarré ici.
-    server::run();
+    server::run(ServerConfig::from_env());
 }
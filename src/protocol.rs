@@ -0,0 +1,166 @@
+use serde::{Serialize, Deserialize};
+pub use crate::i18n::Language;
+
+// Enumération représentant les niveaux de difficulté possibles du jeu.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    // Renvoie la borne supérieure de la plage du nombre secret pour ce niveau de difficulté (la borne inférieure est 1).
+    pub fn max_number(self) -> u32 {
+        match self {
+            Difficulty::Easy => 100, // Facile: 1 à 100.
+            Difficulty::Medium => 500, // Moyen: 1 à 500.
+            Difficulty::Hard => 1000, // Difficile: 1 à 1000.
+        }
+    }
+}
+
+// Enumération représentant les différentes phases du jeu.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum GamePhase {
+    Identification, // Phase où les joueurs rejoignent le jeu.
+    Voting, // Phase où les joueurs votent pour la difficulté.
+    Playing, // Phase où les joueurs jouent (devinent le nombre secret).
+}
+
+// Enumération représentant les raisons pour lesquelles une devinette peut être refusée.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum GuessError {
+    WrongPhase, // La partie n'est pas en phase de jeu.
+    NotYourTurn, // En mode tour par tour, ce n'est pas au tour de ce joueur.
+    Spectating, // Le client regarde la partie en spectateur et ne peut pas deviner.
+    NextRound, // Le joueur est arrivé en cours de manche et attend la manche suivante.
+}
+
+// Enumération représentant les raisons pour lesquelles un message du chat peut être refusé.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum ChatError {
+    Empty, // Le message est vide.
+    TooLong(u32), // Le message dépasse la longueur maximale (en caractères) donnée.
+    RateLimited, // Le joueur envoie trop de messages.
+    Blocked, // Le message contient un mot interdit.
+    UnknownRecipient, // Le destinataire d'un message privé n'est pas connecté.
+}
+
+// Enumération représentant les commandes d'administration de la partie, réservées à l'hôte.
+#[derive(Clone, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum AdminCommand {
+    Kick(String), // Exclut un joueur (ou un spectateur) de la partie.
+    Ban(String), // Exclut un joueur et refuse son nom, ou une adresse IP, jusqu'à l'arrêt du serveur.
+    ForceStart, // Lance le match sans attendre que les joueurs soient prêts.
+    SkipVoting, // Termine la phase de vote immédiatement : la manche commence.
+    EndRound, // Termine la manche en cours et révèle le nombre secret.
+    SetDifficulty(Difficulty), // Impose la difficulté de la prochaine manche, quels que soient les votes.
+    TransferHost(String), // Donne le rôle d'hôte à un autre joueur.
+}
+
+// Enumération représentant les raisons pour lesquelles une commande d'administration peut être refusée.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum AdminError {
+    NotHost, // Seul l'hôte peut administrer la partie.
+    WrongPhase, // La commande n'a pas de sens dans la phase en cours.
+    UnknownPlayer, // Le joueur visé n'est pas dans la partie.
+    OwnPlayer, // L'hôte ne peut pas s'exclure lui-même.
+    NoPlayers, // Il n'y a aucun joueur pour lancer le match.
+}
+
+// Enumération représentant les raisons pour lesquelles une connexion ou une création de compte peut être refusée.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum AuthError {
+    WrongCredentials, // Le nom ou le mot de passe est incorrect.
+    NameTaken, // Un compte existe déjà avec ce nom.
+    NameReserved, // Le nom appartient à un compte : il faut s'y connecter pour l'utiliser.
+    GuestsNotAllowed, // Le serveur n'accepte que les joueurs connectés à un compte.
+    InvalidName, // Le nom ne peut pas être utilisé pour un compte.
+    WeakPassword, // Le mot de passe est trop court.
+    Unavailable, // Les comptes ne peuvent pas être lus ou enregistrés sur le serveur.
+    NameInUse, // Un client connecté utilise déjà ce nom (joueur ou spectateur).
+    TooManyAttempts, // Trop de tentatives de connexion ou de création de compte ont échoué : le serveur ferme la connexion.
+}
+
+// Enumération représentant les indices que le serveur peut donner après une devinette.
+// Les indices sont structurés pour que chaque client puisse les afficher à sa façon.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum Hint {
+    Higher, // Le nombre secret est plus grand que la devinette.
+    Lower, // Le nombre secret est plus petit que la devinette.
+    Correct, // La devinette est correcte.
+    Temperature(Temperature), // Distance entre la devinette et le nombre secret, par tranches (chaud/froid).
+    Parity { even: bool }, // Parité du nombre secret.
+    Divisible { divisor: u32, divisible: bool }, // Le nombre secret est-il divisible par `divisor` ?
+    DigitSum(u32), // Somme des chiffres du nombre secret.
+    Within { distance: u32, within: bool }, // La devinette est-elle à moins de `distance` du nombre secret ?
+}
+
+// Enumération représentant les tranches de distance des indices "chaud/froid".
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum Temperature {
+    Burning, // À 1 % de la plage ou moins.
+    Hot, // À 5 % de la plage ou moins.
+    Warm, // À 10 % de la plage ou moins.
+    Cold, // À 25 % de la plage ou moins.
+    Freezing, // Plus loin.
+}
+
+// `ServerMessage` définit les différents types de messages que le serveur peut envoyer aux clients.
+// Chaque variant correspond à un type de message particulier avec des données spécifiques associées.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum ServerMessage {
+    TimerStart(u32),                // Démarrage du minuteur avec un temps donné (en secondes).
+    GameStart(Difficulty),           // Indique que le jeu commence avec un niveau de difficulté spécifique.
+    Hint(Vec<Hint>),                 // Envoie les indices correspondant à la devinette du joueur.
+    PlayerWon(String),               // Informe que le joueur avec le nom donné a gagné.
+    GameEnd,                         // Signale la fin du jeu.
+    Guess(u32, String),              // Informe les clients d'une tentative de devinette par un joueur (valeur de la devinette et nom du joueur).
+    GuessWithHint(u32, String, Vec<Hint>), // Comme `Guess`, avec les indices reçus par le joueur (quand les indices sont partagés).
+    DifficultyVote(Difficulty),      // Envoie un vote pour la difficulté du jeu.
+    PlayerList { players: Vec<String>, spectators: Vec<String>, waiting: Vec<String> }, // Envoie la liste des joueurs connectés, celle des spectateurs et celle des joueurs qui attendent la manche suivante.
+    VotingCountdown(u32),            // Temps restant (en secondes) avant la fin de la phase de vote.
+    TimeRemaining(u32),              // Temps restant (en secondes) avant la fin de la manche.
+    RoundTimeout { secret: u32 },    // La manche est terminée faute de temps, le nombre secret est révélé.
+    Standings { round: u32, rounds: u32, standings: Vec<(String, u32)> }, // Classement du match après une manche (manche terminée, nombre de manches, scores).
+    MatchEnd { standings: Vec<(String, u32)>, winner: Option<String> },    // Fin du match avec le classement final et le vainqueur (None en cas d'égalité).
+    Turn(String),                    // En mode tour par tour, annonce le joueur dont c'est le tour.
+    TurnSkipped(String),             // En mode tour par tour, le joueur donné n'a pas deviné à temps et passe son tour.
+    GuessRejected(GuessError),       // La devinette du joueur a été refusée (hors phase de jeu, pas son tour...).
+    RaceProgress(String, u32),       // En mode course, nombre de tentatives d'un joueur (sans révéler ses devinettes).
+    RaceTimeout { secrets: Vec<(String, u32)> }, // En mode course, la manche est terminée faute de temps : révèle le nombre secret de chaque joueur.
+    Notice(String),                  // Message d'information destiné au joueur, déjà traduit dans sa langue.
+    Leaderboard(Vec<(String, u32)>), // Classement actuel du match, en réponse à `RequestLeaderboard`.
+    ChatMessage { from: String, text: String, timestamp: u64 }, // Message du chat (horodatage en secondes depuis le 1er janvier 1970, UTC).
+    Whisper { from: String, to: String, text: String, timestamp: u64 }, // Message privé, envoyé au destinataire et à l'expéditeur.
+    ChatRejected(ChatError),         // Le message du joueur a été refusé (trop long, trop de messages...).
+    SecretRevealed(u32),             // Envoyé aux spectateurs à la fin d'une manche gagnée : le nombre secret qui a été trouvé.
+    Lobby { host: Option<String>, ready: Vec<String>, min_players: u32, countdown: Option<u32> }, // État du salon d'attente : hôte, joueurs prêts, nombre minimal de joueurs et délai avant le lancement automatique (en secondes).
+    GameState { phase: GamePhase, difficulty: Option<Difficulty>, round: u32, rounds: u32, remaining: Option<u32> }, // État de la partie envoyé à un client qui arrive en cours de vote ou de manche (difficulté de la manche en cours, temps restant en secondes).
+    AdminAction { by: Option<String>, action: AdminCommand }, // Annonce une commande d'administration exécutée par l'hôte (ou par le serveur lui-même si `by` vaut None).
+    AdminRejected(AdminError),       // La commande d'administration du joueur a été refusée (il n'est pas l'hôte, mauvaise phase...).
+    Announcement(String),            // Annonce envoyée à tous les clients depuis la console d'administration du serveur.
+    ServerShutdown { reason: Option<String>, seconds: u32 }, // Le serveur s'arrête dans le nombre de secondes donné (le temps de finir la manche en cours), avec la raison éventuelle.
+    Authenticated(String),           // Le client est connecté au compte donné : il peut rejoindre la partie sous ce nom.
+    AuthRejected(AuthError),         // La connexion, la création de compte ou l'arrivée dans la partie a été refusée.
+}
+
+// `ClientMessage` définit les différents types de messages que le client peut envoyer au serveur.
+// Chaque variant correspond à un type de message particulier avec des données spécifiques associées.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum ClientMessage {
+    Guess(u32),                      // Envoie une devinette avec une valeur numérique.
+    DifficultyVote(Difficulty),      // Envoie un vote pour une difficulté spécifique.
+    Join { name: String, language: Language }, // Requête pour rejoindre le jeu avec un nom de joueur et sa langue préférée.
+    RequestPlayers,                  // Demande la liste des joueurs actuellement connectés.
+    RequestLeaderboard,              // Demande le classement actuel du match.
+    Chat(String),                    // Envoie un message à tous les joueurs.
+    Whisper { to: String, text: String }, // Envoie un message privé à un joueur.
+    JoinAsSpectator { name: String, language: Language }, // Requête pour regarder la partie sans y participer (ni vote, ni devinette).
+    Ready,                           // Dans le salon d'attente, se déclare prêt (ou ne l'est plus).
+    StartMatch,                      // Dans le salon d'attente, l'hôte lance le match sans attendre que tous les joueurs soient prêts.
+    Admin(AdminCommand),             // Commande d'administration de la partie (réservée à l'hôte).
+    Login { name: String, password: String }, // Se connecte à un compte avant de rejoindre la partie sous son nom.
+    Register { name: String, password: String }, // Crée un compte (et s'y connecte) pour réserver un nom.
+}
@@ -0,0 +1,83 @@
+use crate::protocol::Hint;
+
+// `KnownRange` représente l'intervalle dans lequel le nombre secret se trouve encore,
+// d'après les indices reçus par le client pendant la manche.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KnownRange {
+    pub low: u32, // Plus petite valeur encore possible.
+    pub high: u32, // Plus grande valeur encore possible.
+}
+
+impl KnownRange {
+    // Crée l'intervalle de départ d'une manche : de 1 au nombre maximal de la difficulté.
+    pub fn new(max: u32) -> KnownRange {
+        KnownRange { low: 1, high: max.max(1) }
+    }
+
+    // Resserre l'intervalle à partir des indices reçus pour une devinette.
+    // Les indices qui ne permettent pas de borner l'intervalle (parité, température...) sont ignorés.
+    pub fn apply(&mut self, guess: u32, hints: &[Hint]) {
+        for hint in hints {
+            match *hint {
+                Hint::Higher => self.low = self.low.max(guess.saturating_add(1)),
+                Hint::Lower => self.high = self.high.min(guess.saturating_sub(1)),
+                Hint::Correct => {
+                    self.low = guess;
+                    self.high = guess;
+                },
+                Hint::Within { distance, within: true } => {
+                    self.low = self.low.max(guess.saturating_sub(distance));
+                    self.high = self.high.min(guess.saturating_add(distance));
+                },
+                _ => {},
+            }
+        }
+        if self.low > self.high {
+            // Indices contradictoires (par exemple un indice d'une manche précédente) : on garde la dernière borne.
+            self.high = self.low;
+        }
+    }
+
+    // Indique si une devinette est dans l'intervalle encore possible.
+    pub fn contains(&self, guess: u32) -> bool {
+        (self.low..=self.high).contains(&guess)
+    }
+
+    // Renvoie la devinette optimale : le milieu de l'intervalle, comme pour une recherche dichotomique.
+    pub fn optimal_guess(&self) -> u32 {
+        self.low + (self.high - self.low) / 2
+    }
+}
+
+// Tests unitaires pour le module range
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour le resserrement de l'intervalle avec les indices "plus grand" et "plus petit"
+    #[test]
+    fn test_apply_higher_lower() {
+        let mut range = KnownRange::new(100);
+        range.apply(50, &[Hint::Higher]);
+        assert_eq!(range, KnownRange { low: 51, high: 100 });
+        range.apply(75, &[Hint::Lower, Hint::Parity { even: true }]);
+        assert_eq!(range, KnownRange { low: 51, high: 74 });
+        assert!(range.contains(60));
+        assert!(!range.contains(50));
+        assert!(!range.contains(75));
+        assert_eq!(range.optimal_guess(), 62);
+    }
+
+    // Test pour les indices de distance et la bonne réponse
+    #[test]
+    fn test_apply_within_and_correct() {
+        let mut range = KnownRange::new(500);
+        range.apply(100, &[Hint::Higher, Hint::Within { distance: 10, within: true }]);
+        assert_eq!(range, KnownRange { low: 101, high: 110 });
+        range.apply(300, &[Hint::Lower, Hint::Within { distance: 10, within: false }]);
+        assert_eq!(range, KnownRange { low: 101, high: 110 });
+        range.apply(105, &[Hint::Correct]);
+        assert_eq!(range, KnownRange { low: 105, high: 105 });
+        assert_eq!(range.optimal_guess(), 105);
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use crate::protocol::AuthError;
+
+const MIN_PASSWORD_LEN: usize = 8; // Longueur minimale d'un mot de passe, en caractères.
+pub const MAX_AUTH_FAILURES: u32 = 5; // Nombre d'échecs de connexion ou de création de compte avant la fermeture de la connexion.
+// Hachage d'un mot de passe quelconque, avec les paramètres par défaut d'argon2 : il est vérifié quand le nom est inconnu,
+// pour que le temps de réponse ne révèle pas quels noms ont un compte.
+const DUMMY_HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$DqwriA38mfS2CTEibncZ4A$Wn3nlXWMyQfqkrvtSo9Sj5oN8ZeOElo/c7omE5vW19I";
+const AUTH_FAILURE_DELAY: Duration = Duration::from_millis(200); // Attente ajoutée après chaque échec.
+
+// Temps d'attente avant de répondre au client après son n-ième échec : il augmente à chaque échec,
+// pour ralentir la recherche d'un mot de passe par essais successifs.
+pub fn failure_delay(failures: u32) -> Duration {
+    AUTH_FAILURE_DELAY * failures
+}
+
+// `Accounts` contient les comptes des joueurs enregistrés : leur nom est réservé et protégé par un mot de passe.
+// Les mots de passe sont hachés avec argon2 et un sel aléatoire ; les comptes sont enregistrés dans un fichier local,
+// une ligne par compte (nom et hachage séparés par une tabulation).
+pub struct Accounts {
+    pub allow_guests: bool, // Les joueurs sans compte peuvent rejoindre la partie avec un nom qui n'est pas réservé.
+    path: Option<PathBuf>, // Fichier des comptes (None : les comptes sont perdus à l'arrêt du serveur).
+    hashes: Mutex<HashMap<String, String>>, // Hachage du mot de passe de chaque compte, par nom de joueur (en minuscules).
+}
+
+impl Accounts {
+    // Crée une liste de comptes vide, qui n'est pas enregistrée.
+    pub fn new(allow_guests: bool) -> Accounts {
+        Accounts { allow_guests, path: None, hashes: Mutex::new(HashMap::new()) }
+    }
+
+    // Charge les comptes depuis un fichier (qui n'existe pas forcément encore) ; les nouveaux comptes y seront ajoutés.
+    pub fn load(path: PathBuf, allow_guests: bool) -> io::Result<Accounts> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error),
+        };
+        let mut hashes = HashMap::new();
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            match line.split_once('\t') {
+                Some((name, hash)) => {
+                    hashes.insert(name_key(name), hash.to_string());
+                },
+                None => eprintln!("Ignoring invalid line in {}: {}", path.display(), line),
+            }
+        }
+        Ok(Accounts { allow_guests, path: Some(path), hashes: Mutex::new(hashes) })
+    }
+
+    // Indique si le nom est réservé par un compte, sans tenir compte des majuscules.
+    pub fn is_registered(&self, name: &str) -> bool {
+        self.hashes.lock().unwrap().contains_key(&name_key(name))
+    }
+
+    // Crée un compte. Le hachage, volontairement lent, est calculé sans bloquer les autres connexions.
+    pub fn register(&self, name: &str, password: &str) -> Result<(), AuthError> {
+        if name.is_empty() || name.contains(['\t', '\n', '\r']) {
+            return Err(AuthError::InvalidName);
+        }
+        if password.chars().count() < MIN_PASSWORD_LEN {
+            return Err(AuthError::WeakPassword);
+        }
+        if self.is_registered(name) {
+            return Err(AuthError::NameTaken);
+        }
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = Argon2::default().hash_password(password.as_bytes(), &salt).map_err(|_| AuthError::Unavailable)?.to_string();
+
+        let mut hashes = self.hashes.lock().unwrap();
+        if hashes.contains_key(&name_key(name)) {
+            return Err(AuthError::NameTaken); // Le nom a été réservé pendant le calcul du hachage.
+        }
+        if let Some(path) = &self.path {
+            let saved = OpenOptions::new().create(true).append(true).open(path).and_then(|mut file| writeln!(file, "{}\t{}", name, hash));
+            if let Err(error) = saved {
+                eprintln!("Could not save the account {} to {}: {}", name, path.display(), error);
+                return Err(AuthError::Unavailable);
+            }
+        }
+        hashes.insert(name_key(name), hash);
+        Ok(())
+    }
+
+    // Vérifie le mot de passe d'un compte. Un nom inconnu et un mauvais mot de passe donnent la même erreur,
+    // après le même calcul : le mot de passe est alors comparé à un hachage factice.
+    pub fn verify(&self, name: &str, password: &str) -> Result<(), AuthError> {
+        let Some(hash) = self.hashes.lock().unwrap().get(&name_key(name)).cloned() else {
+            let hash = PasswordHash::new(DUMMY_HASH).map_err(|_| AuthError::Unavailable)?;
+            Argon2::default().verify_password(password.as_bytes(), &hash).ok();
+            return Err(AuthError::WrongCredentials);
+        };
+        let hash = PasswordHash::new(&hash).map_err(|_| AuthError::Unavailable)?;
+        Argon2::default().verify_password(password.as_bytes(), &hash).map_err(|_| AuthError::WrongCredentials)
+    }
+
+    // Vérifie qu'un client peut rejoindre la partie sous ce nom : un nom réservé exige d'être connecté au compte,
+    // et un invité n'est accepté que si la configuration le permet.
+    pub fn check_join(&self, name: &str, authenticated: Option<&str>) -> Result<(), AuthError> {
+        if authenticated.is_some_and(|authenticated| name_key(authenticated) == name_key(name)) {
+            return Ok(());
+        }
+        if self.is_registered(name) {
+            return Err(AuthError::NameReserved);
+        }
+        if !self.allow_guests {
+            return Err(AuthError::GuestsNotAllowed);
+        }
+        Ok(())
+    }
+}
+
+// Forme d'un nom utilisée pour le comparer aux autres : le nom en minuscules, pour que « alice » ne puisse pas
+// usurper le compte ou la place d'« Alice ». Le fichier des comptes garde le nom tel qu'il a été choisi.
+pub fn name_key(name: &str) -> String {
+    name.to_lowercase()
+}
+
+// Tests unitaires pour le module accounts
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour la création d'un compte et la vérification du mot de passe
+    #[test]
+    fn test_register_and_verify() {
+        let accounts = Accounts::new(true);
+        assert_eq!(accounts.register("Alice", "short"), Err(AuthError::WeakPassword));
+        assert_eq!(accounts.register("", "long enough"), Err(AuthError::InvalidName));
+        assert_eq!(accounts.register("Alice", "correct horse"), Ok(()));
+        assert_eq!(accounts.register("Alice", "another password"), Err(AuthError::NameTaken));
+        assert_eq!(accounts.register("ALICE", "another password"), Err(AuthError::NameTaken));
+        assert_eq!(accounts.verify("Alice", "correct horse"), Ok(()));
+        assert_eq!(accounts.verify("Alice", "wrong password"), Err(AuthError::WrongCredentials));
+        assert_eq!(accounts.verify("Bob", "correct horse"), Err(AuthError::WrongCredentials));
+        assert_eq!(accounts.verify("alice", "correct horse"), Ok(()));
+    }
+
+    // Test pour les noms réservés et les invités
+    #[test]
+    fn test_check_join() {
+        let mut accounts = Accounts::new(true);
+        accounts.register("Alice", "correct horse").unwrap();
+        assert_eq!(accounts.check_join("Alice", None), Err(AuthError::NameReserved));
+        assert_eq!(accounts.check_join("Alice", Some("Alice")), Ok(()));
+        assert_eq!(accounts.check_join("alice", None), Err(AuthError::NameReserved)); // Les majuscules ne changent pas le nom
+        assert_eq!(accounts.check_join("aLiCe", Some("Alice")), Ok(()));
+        assert_eq!(accounts.check_join("Bob", None), Ok(()));
+        accounts.allow_guests = false;
+        assert_eq!(accounts.check_join("Bob", None), Err(AuthError::GuestsNotAllowed));
+        assert_eq!(accounts.check_join("Bob", Some("Alice")), Err(AuthError::GuestsNotAllowed));
+    }
+
+    // Test pour le hachage factice vérifié quand le nom est inconnu : il doit coûter autant qu'un vrai hachage
+    #[test]
+    fn test_dummy_hash() {
+        let dummy = PasswordHash::new(DUMMY_HASH).unwrap();
+        let salt = SaltString::generate(&mut OsRng);
+        let real = Argon2::default().hash_password(b"correct horse", &salt).unwrap();
+        assert_eq!(dummy.algorithm, real.algorithm);
+        assert_eq!(dummy.params, real.params);
+    }
+
+    // Test pour l'attente croissante après chaque échec
+    #[test]
+    fn test_failure_delay() {
+        assert_eq!(failure_delay(0), Duration::ZERO);
+        assert_eq!(failure_delay(1), AUTH_FAILURE_DELAY);
+        assert!(failure_delay(MAX_AUTH_FAILURES) > failure_delay(MAX_AUTH_FAILURES - 1));
+    }
+
+    // Test pour l'enregistrement des comptes dans un fichier
+    #[test]
+    fn test_load() {
+        let path = std::env::temp_dir().join(format!("number_game_accounts_{}.txt", std::process::id()));
+        fs::remove_file(&path).ok();
+        let accounts = Accounts::load(path.clone(), true).unwrap();
+        accounts.register("Alice", "correct horse").unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with("Alice\t$argon2id$"));
+        assert!(!contents.contains("correct horse"));
+        let accounts = Accounts::load(path.clone(), true).unwrap();
+        assert!(accounts.is_registered("Alice"));
+        assert!(accounts.is_registered("alice"));
+        assert_eq!(accounts.verify("Alice", "correct horse"), Ok(()));
+        fs::remove_file(&path).ok();
+    }
+}
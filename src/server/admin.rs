@@ -0,0 +1,134 @@
+use std::collections::BTreeSet;
+use std::net::{IpAddr, Shutdown};
+use crate::protocol::{AdminCommand, AdminError, GamePhase, ServerMessage};
+use crate::server::accounts::name_key;
+use crate::server::game::Game;
+use crate::server::util::{broadcast_message, Connection};
+use crate::server::{lobby_state, start_round, stop_round};
+
+// `Bans` contient les noms et les adresses IP exclus de la partie. Les exclusions durent jusqu'à l'arrêt du serveur.
+#[derive(Default)]
+pub struct Bans {
+    names: BTreeSet<String>, // Noms de joueurs refusés (en minuscules, comme les comptes).
+    addresses: BTreeSet<IpAddr>, // Adresses IP refusées.
+}
+
+impl Bans {
+    // Exclut un nom de joueur (quelles que soient ses majuscules), ou une adresse IP si la cible en est une.
+    pub fn ban(&mut self, target: &str) {
+        match target.parse::<IpAddr>() {
+            Ok(address) => self.addresses.insert(address),
+            Err(_) => self.names.insert(name_key(target)),
+        };
+    }
+
+    // Indique si un client portant ce nom, connecté depuis cette adresse, est exclu.
+    pub fn is_banned(&self, name: &str, address: Option<IpAddr>) -> bool {
+        self.names.contains(&name_key(name)) || address.is_some_and(|address| self.addresses.contains(&address))
+    }
+}
+
+// Vérifie que le joueur a le droit d'administrer la partie : seul l'hôte peut le faire.
+pub fn authorize(game: &Game, name: &str) -> Result<(), AdminError> {
+    if game.lobby.host() != Some(name) {
+        return Err(AdminError::NotHost);
+    }
+    Ok(())
+}
+
+// Exécute une commande d'administration au nom de l'hôte `by` (None pour le serveur lui-même).
+// La commande est annoncée à tous les clients avant d'être appliquée, pour que les joueurs exclus en soient informés.
+pub fn execute(command: AdminCommand, by: Option<&str>, game: &mut Game, clients: &[Connection]) -> Result<(), AdminError> {
+    check(&command, by, game, clients)?;
+    broadcast_message(ServerMessage::AdminAction { by: by.map(str::to_string), action: command.clone() }, clients);
+    match command {
+        AdminCommand::Kick(name) => disconnect(clients, |client| client.name == name),
+        AdminCommand::Ban(target) => {
+            game.bans.ban(&target);
+            match target.parse::<IpAddr>() {
+                // Tous les clients connectés depuis cette adresse sont exclus, sauf celui qui a donné l'ordre.
+                Ok(address) => disconnect(clients, |client| client.address.map(|a| a.ip()) == Some(address) && Some(client.name.as_str()) != by),
+                Err(_) => disconnect(clients, |client| name_key(&client.name) == name_key(&target)),
+            }
+        },
+        AdminCommand::ForceStart => game.start_voting_phase(),
+        AdminCommand::SkipVoting => start_round(game, clients),
+        AdminCommand::EndRound => stop_round(game, clients),
+        AdminCommand::SetDifficulty(difficulty) => game.force_difficulty(difficulty),
+        AdminCommand::TransferHost(name) => {
+            game.lobby.transfer_host(&name);
+            if game.in_lobby() {
+                broadcast_message(lobby_state(game), clients); // Les joueurs voient le nouvel hôte
+            }
+        },
+    }
+    Ok(())
+}
+
+// Vérifie que la commande peut être exécutée dans l'état actuel de la partie.
+fn check(command: &AdminCommand, by: Option<&str>, game: &Game, clients: &[Connection]) -> Result<(), AdminError> {
+    match command {
+        AdminCommand::Kick(name) | AdminCommand::Ban(name) if by.is_some_and(|by| name_key(by) == name_key(name)) => Err(AdminError::OwnPlayer),
+        AdminCommand::Kick(name) if !clients.iter().any(|client| client.name == *name) => Err(AdminError::UnknownPlayer),
+        AdminCommand::ForceStart if game.phase != GamePhase::Identification => Err(AdminError::WrongPhase),
+        AdminCommand::ForceStart if game.players.is_empty() => Err(AdminError::NoPlayers),
+        AdminCommand::SkipVoting if game.phase != GamePhase::Voting => Err(AdminError::WrongPhase),
+        AdminCommand::EndRound if game.phase != GamePhase::Playing => Err(AdminError::WrongPhase),
+        AdminCommand::SetDifficulty(_) if game.phase == GamePhase::Playing => Err(AdminError::WrongPhase), // La difficulté de la manche en cours est déjà fixée.
+        AdminCommand::TransferHost(name) if !game.lobby.contains(name) => Err(AdminError::UnknownPlayer),
+        _ => Ok(()),
+    }
+}
+
+// Ferme la connexion des clients visés. Leur thread détecte la fermeture et les retire de la partie.
+fn disconnect(clients: &[Connection], target: impl Fn(&Connection) -> bool) {
+    for client in clients.iter().filter(|client| target(client)) {
+        client.stream.shutdown(Shutdown::Both).ok();
+    }
+}
+
+// Tests unitaires pour le module admin
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::Difficulty;
+
+    // Test pour les exclusions par nom et par adresse
+    #[test]
+    fn test_bans() {
+        let mut bans = Bans::default();
+        let address: IpAddr = "10.0.0.7".parse().unwrap();
+        assert!(!bans.is_banned("Bob", Some(address)));
+        bans.ban("Bob");
+        bans.ban("10.0.0.7");
+        assert!(bans.is_banned("Bob", None));
+        assert!(bans.is_banned("bOB", None)); // Les majuscules ne changent pas le nom
+        assert!(bans.is_banned("Carol", Some(address)));
+        assert!(!bans.is_banned("Carol", Some("10.0.0.8".parse().unwrap())));
+    }
+
+    // Test pour les droits et les phases des commandes d'administration
+    #[test]
+    fn test_authorize_and_check() {
+        let mut game = Game::new();
+        game.add_player("Alice".to_string());
+        game.add_player("Bob".to_string());
+        assert_eq!(authorize(&game, "Alice"), Ok(()));
+        assert_eq!(authorize(&game, "Bob"), Err(AdminError::NotHost));
+        assert_eq!(execute(AdminCommand::Kick("Alice".to_string()), Some("Alice"), &mut game, &[]), Err(AdminError::OwnPlayer));
+        assert_eq!(execute(AdminCommand::Ban("alice".to_string()), Some("Alice"), &mut game, &[]), Err(AdminError::OwnPlayer));
+        assert_eq!(execute(AdminCommand::Kick("Zoe".to_string()), Some("Alice"), &mut game, &[]), Err(AdminError::UnknownPlayer));
+        assert_eq!(execute(AdminCommand::SkipVoting, Some("Alice"), &mut game, &[]), Err(AdminError::WrongPhase));
+        assert_eq!(execute(AdminCommand::TransferHost("Bob".to_string()), Some("Alice"), &mut game, &[]), Ok(()));
+        assert_eq!(authorize(&game, "Bob"), Ok(()));
+        assert_eq!(execute(AdminCommand::SetDifficulty(Difficulty::Hard), None, &mut game, &[]), Ok(()));
+        assert_eq!(execute(AdminCommand::ForceStart, None, &mut game, &[]), Ok(()));
+        assert_eq!(game.phase, GamePhase::Voting);
+        assert_eq!(execute(AdminCommand::SkipVoting, None, &mut game, &[]), Ok(()));
+        assert_eq!(game.phase, GamePhase::Playing);
+        assert_eq!(game.difficulty, Difficulty::Hard);
+        assert_eq!(execute(AdminCommand::SetDifficulty(Difficulty::Easy), None, &mut game, &[]), Err(AdminError::WrongPhase));
+        assert_eq!(execute(AdminCommand::EndRound, None, &mut game, &[]), Ok(()));
+        assert_eq!(game.phase, GamePhase::Voting); // Le match d'une seule manche est terminé : un nouveau vote commence.
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use crate::protocol::ChatError;
+
+// Trait implémenté par les filtres de messages du chat (mots interdits, grossièretés...).
+// Un filtre renvoie le texte à diffuser (éventuellement modifié), ou None pour refuser le message.
+pub trait ChatFilter: Send {
+    fn filter(&self, text: &str) -> Option<String>;
+}
+
+// Filtre qui laisse passer tous les messages.
+pub struct NoFilter;
+
+impl ChatFilter for NoFilter {
+    fn filter(&self, text: &str) -> Option<String> {
+        Some(text.to_string())
+    }
+}
+
+// Filtre de mots interdits : les mots sont masqués par des étoiles, ou le message est refusé.
+pub struct BlockedWords {
+    words: Vec<String>, // Mots interdits, en minuscules.
+    reject: bool, // Refuser le message plutôt que masquer les mots.
+}
+
+impl BlockedWords {
+    pub fn new(words: Vec<String>, reject: bool) -> BlockedWords {
+        BlockedWords { words: words.into_iter().map(|word| word.to_lowercase()).filter(|word| !word.is_empty()).collect(), reject }
+    }
+
+    // Indique si un mot du message est interdit (sans tenir compte de la casse ni de la ponctuation qui l'entoure).
+    fn is_blocked(&self, word: &str) -> bool {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase();
+        self.words.contains(&word)
+    }
+}
+
+impl ChatFilter for BlockedWords {
+    fn filter(&self, text: &str) -> Option<String> {
+        let mut blocked = false;
+        let filtered: Vec<String> = text.split(' ').map(|word| {
+            if self.is_blocked(word) {
+                blocked = true;
+                "*".repeat(word.chars().count())
+            } else {
+                word.to_string()
+            }
+        }).collect();
+        if blocked && self.reject {
+            return None;
+        }
+        Some(filtered.join(" "))
+    }
+}
+
+// Limiteur de débit : au plus `limit` messages par joueur sur une fenêtre glissante de durée `window`.
+pub struct RateLimiter {
+    limit: usize, // Nombre maximal de messages dans la fenêtre.
+    window: Duration, // Durée de la fenêtre.
+    sent: HashMap<String, VecDeque<Instant>>, // Instants des derniers messages de chaque joueur.
+}
+
+impl RateLimiter {
+    pub fn new(limit: usize, window: Duration) -> RateLimiter {
+        RateLimiter { limit, window, sent: HashMap::new() }
+    }
+
+    // Enregistre un message du joueur s'il est autorisé, et indique s'il l'est.
+    pub fn allow(&mut self, player: &str, now: Instant) -> bool {
+        let sent = self.sent.entry(player.to_string()).or_default();
+        while sent.front().is_some_and(|&instant| now.duration_since(instant) >= self.window) {
+            sent.pop_front(); // Oublie les messages sortis de la fenêtre.
+        }
+        if sent.len() >= self.limit {
+            return false;
+        }
+        sent.push_back(now);
+        true
+    }
+
+    // Oublie l'historique d'un joueur (à sa déconnexion).
+    pub fn forget(&mut self, player: &str) {
+        self.sent.remove(player);
+    }
+}
+
+// `Chat` vérifie les messages du chat avant leur diffusion : longueur, débit et filtre.
+pub struct Chat {
+    max_length: usize, // Longueur maximale d'un message, en caractères.
+    limiter: RateLimiter, // Limite du nombre de messages par joueur.
+    filter: Box<dyn ChatFilter>, // Filtre appliqué au texte des messages.
+}
+
+impl Chat {
+    pub fn new(max_length: usize, limiter: RateLimiter, filter: Box<dyn ChatFilter>) -> Chat {
+        Chat { max_length, limiter, filter }
+    }
+
+    // Vérifie un message d'un joueur et renvoie le texte à diffuser, ou la raison du refus.
+    pub fn check(&mut self, player: &str, text: &str, now: Instant) -> Result<String, ChatError> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(ChatError::Empty);
+        }
+        if text.chars().count() > self.max_length {
+            return Err(ChatError::TooLong(self.max_length as u32));
+        }
+        if !self.limiter.allow(player, now) {
+            return Err(ChatError::RateLimited);
+        }
+        self.filter.filter(text).ok_or(ChatError::Blocked)
+    }
+
+    // Oublie l'historique d'un joueur (à sa déconnexion).
+    pub fn forget(&mut self, player: &str) {
+        self.limiter.forget(player);
+    }
+}
+
+impl Default for Chat {
+    // 200 caractères au plus, 5 messages toutes les 10 secondes, sans filtre.
+    fn default() -> Chat {
+        Chat::new(200, RateLimiter::new(5, Duration::from_secs(10)), Box::new(NoFilter))
+    }
+}
+
+// Tests unitaires pour le module chat
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour le filtre de mots interdits
+    #[test]
+    fn test_blocked_words() {
+        let mask = BlockedWords::new(vec!["Darn".to_string()], false);
+        assert_eq!(mask.filter("oh darn, DARN it"), Some("oh ***** **** it".to_string()));
+        assert_eq!(mask.filter("darning needle"), Some("darning needle".to_string()));
+        let reject = BlockedWords::new(vec!["darn".to_string()], true);
+        assert_eq!(reject.filter("oh darn"), None);
+        assert_eq!(reject.filter("hello"), Some("hello".to_string()));
+    }
+
+    // Test pour la limite de débit sur une fenêtre glissante
+    #[test]
+    fn test_rate_limiter() {
+        let mut limiter = RateLimiter::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+        assert!(limiter.allow("Alice", start));
+        assert!(limiter.allow("Alice", start + Duration::from_secs(1)));
+        assert!(!limiter.allow("Alice", start + Duration::from_secs(2)));
+        assert!(limiter.allow("Bob", start + Duration::from_secs(2)));
+        assert!(limiter.allow("Alice", start + Duration::from_secs(10)));
+    }
+
+    // Test pour la vérification complète d'un message
+    #[test]
+    fn test_check() {
+        let mut chat = Chat::new(10, RateLimiter::new(5, Duration::from_secs(10)), Box::new(BlockedWords::new(vec!["darn".to_string()], true)));
+        let now = Instant::now();
+        assert_eq!(chat.check("Alice", "  hi  ", now), Ok("hi".to_string()));
+        assert_eq!(chat.check("Alice", "   ", now), Err(ChatError::Empty));
+        assert_eq!(chat.check("Alice", "hello world!", now), Err(ChatError::TooLong(10)));
+        assert_eq!(chat.check("Alice", "darn", now), Err(ChatError::Blocked));
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::env;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use crate::protocol::Difficulty;
+use crate::server::game::{GameMode, HintVisibility, LateJoinPolicy};
+use crate::server::hint::{parse_hint_kinds, HintKind};
+
+// `ServerConfig` regroupe les paramètres configurables du serveur.
+// Les valeurs sont lues depuis les variables d'environnement (ou un fichier `.env` grâce à dotenv).
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub voting_duration: Duration,        // Durée de la phase de vote (VOTING_DURATION, en secondes).
+    pub round_duration: Option<Duration>, // Durée maximale d'une manche (ROUND_DURATION, en secondes, 0 = illimitée).
+    pub rounds_per_match: u32,            // Nombre de manches dans un match (ROUNDS_PER_MATCH).
+    pub mode: GameMode,                   // Mode de jeu (GAME_MODE : "simultaneous" ou "turn").
+    pub turn_duration: Duration,          // Temps accordé à chaque tour en mode tour par tour (TURN_DURATION, en secondes).
+    pub hint_visibility: HintVisibility,  // Ce que les autres joueurs voient des devinettes (HINT_VISIBILITY : "private", "guess" ou "shared").
+    pub hints: HashMap<Difficulty, Vec<HintKind>>, // Indices supplémentaires par difficulté (HINTS, ou HINTS_EASY / HINTS_MEDIUM / HINTS_HARD).
+    pub chat_max_length: usize,           // Longueur maximale d'un message du chat, en caractères (CHAT_MAX_LENGTH).
+    pub chat_rate_limit: usize,           // Nombre maximal de messages du chat par joueur sur la fenêtre (CHAT_RATE_LIMIT).
+    pub chat_rate_window: Duration,       // Durée de la fenêtre de limitation du chat (CHAT_RATE_WINDOW, en secondes).
+    pub chat_blocked_words: Vec<String>,  // Mots interdits dans le chat (CHAT_BLOCKED_WORDS, séparés par des virgules).
+    pub chat_reject_blocked: bool,        // Refuser les messages contenant un mot interdit plutôt que le masquer (CHAT_BLOCKED_ACTION : "mask" ou "reject").
+    pub spectators_see_secret: bool,      // Révéler le nombre secret aux spectateurs à la fin d'une manche gagnée (SPECTATOR_REVEAL_SECRET).
+    pub lobby: bool,                      // Attendre dans un salon que les joueurs soient prêts avant la phase de vote (LOBBY).
+    pub min_players: usize,               // Nombre minimal de joueurs pour lancer le match depuis le salon (MIN_PLAYERS).
+    pub lobby_countdown: Duration,        // Délai avant le lancement automatique du match une fois tous les joueurs prêts (LOBBY_COUNTDOWN, en secondes).
+    pub host: Option<String>,             // Joueur désigné comme hôte, à la place du premier arrivé (HOST_PLAYER).
+    pub shutdown_grace: Duration,         // Temps laissé à la manche en cours pour se terminer quand le serveur s'arrête (SHUTDOWN_GRACE, en secondes).
+    pub leaderboard_file: Option<PathBuf>, // Fichier où le classement est enregistré à l'arrêt du serveur (LEADERBOARD_FILE).
+    pub console: bool,                    // Lire les commandes d'administration sur l'entrée standard du serveur (ADMIN_CONSOLE).
+    pub accounts_file: Option<PathBuf>,   // Fichier des comptes des joueurs (ACCOUNTS_FILE ; sans fichier, les comptes sont perdus à l'arrêt du serveur).
+    pub allow_guests: bool,               // Accepter les joueurs qui ne sont pas connectés à un compte (ALLOW_GUESTS).
+    pub tls_cert: Option<PathBuf>,        // Certificat du serveur au format PEM : avec TLS_KEY, les connexions sont chiffrées avec TLS (TLS_CERT).
+    pub tls_key: Option<PathBuf>,         // Clé privée du certificat au format PEM (TLS_KEY).
+    pub late_join: LateJoinPolicy,        // Ce qui arrive à un joueur qui rejoint la partie pendant une manche (LATE_JOIN : "immediate", "queue" ou "spectate").
+}
+
+impl Default for ServerConfig {
+    fn default() -> ServerConfig {
+        ServerConfig {
+            voting_duration: Duration::from_secs(20),
+            round_duration: Some(Duration::from_secs(120)),
+            rounds_per_match: 3,
+            mode: GameMode::Simultaneous,
+            turn_duration: Duration::from_secs(15),
+            hint_visibility: HintVisibility::GuessOnly,
+            hints: HashMap::new(),
+            chat_max_length: 200,
+            chat_rate_limit: 5,
+            chat_rate_window: Duration::from_secs(10),
+            chat_blocked_words: vec![],
+            chat_reject_blocked: false,
+            spectators_see_secret: true,
+            lobby: true,
+            min_players: 2,
+            lobby_countdown: Duration::from_secs(10),
+            host: None,
+            shutdown_grace: Duration::from_secs(30),
+            leaderboard_file: None,
+            console: true,
+            accounts_file: None,
+            allow_guests: true,
+            tls_cert: None,
+            tls_key: None,
+            late_join: LateJoinPolicy::Immediate,
+        }
+    }
+}
+
+impl ServerConfig {
+    // Construit la configuration à partir de l'environnement, en gardant les valeurs par défaut pour les variables absentes.
+    pub fn from_env() -> ServerConfig {
+        dotenv::dotenv().ok(); // Le fichier `.env` est optionnel.
+        let default = ServerConfig::default();
+        ServerConfig {
+            voting_duration: read_var("VOTING_DURATION").map(Duration::from_secs).unwrap_or(default.voting_duration),
+            round_duration: match read_var::<u64>("ROUND_DURATION") {
+                Some(0) => None, // 0 désactive la limite de temps.
+                Some(secs) => Some(Duration::from_secs(secs)),
+                None => default.round_duration,
+            },
+            rounds_per_match: read_var("ROUNDS_PER_MATCH").filter(|&rounds| rounds > 0).unwrap_or(default.rounds_per_match),
+            mode: read_var("GAME_MODE").unwrap_or(default.mode),
+            turn_duration: read_var("TURN_DURATION").map(Duration::from_secs).unwrap_or(default.turn_duration),
+            hint_visibility: read_var("HINT_VISIBILITY").unwrap_or(default.hint_visibility),
+            hints: read_hints(),
+            chat_max_length: read_var("CHAT_MAX_LENGTH").filter(|&length| length > 0).unwrap_or(default.chat_max_length),
+            chat_rate_limit: read_var("CHAT_RATE_LIMIT").filter(|&limit| limit > 0).unwrap_or(default.chat_rate_limit),
+            chat_rate_window: read_var("CHAT_RATE_WINDOW").map(Duration::from_secs).unwrap_or(default.chat_rate_window),
+            chat_blocked_words: env::var("CHAT_BLOCKED_WORDS")
+                .map(|words| words.split(',').map(|word| word.trim().to_string()).filter(|word| !word.is_empty()).collect())
+                .unwrap_or(default.chat_blocked_words),
+            chat_reject_blocked: match env::var("CHAT_BLOCKED_ACTION").as_deref() {
+                Ok("reject") => true,
+                Ok("mask") => false,
+                Ok(value) => {
+                    eprintln!("Invalid value for CHAT_BLOCKED_ACTION: {}", value);
+                    default.chat_reject_blocked
+                },
+                Err(_) => default.chat_reject_blocked,
+            },
+            spectators_see_secret: read_var("SPECTATOR_REVEAL_SECRET").unwrap_or(default.spectators_see_secret),
+            lobby: read_var("LOBBY").unwrap_or(default.lobby),
+            min_players: read_var("MIN_PLAYERS").filter(|&players| players > 0).unwrap_or(default.min_players),
+            lobby_countdown: read_var("LOBBY_COUNTDOWN").map(Duration::from_secs).unwrap_or(default.lobby_countdown),
+            host: env::var("HOST_PLAYER").ok().map(|name| name.trim().to_string()).filter(|name| !name.is_empty()),
+            shutdown_grace: read_var("SHUTDOWN_GRACE").map(Duration::from_secs).unwrap_or(default.shutdown_grace),
+            leaderboard_file: env::var("LEADERBOARD_FILE").ok().filter(|path| !path.trim().is_empty()).map(PathBuf::from),
+            console: read_var("ADMIN_CONSOLE").unwrap_or(default.console),
+            accounts_file: env::var("ACCOUNTS_FILE").ok().filter(|path| !path.trim().is_empty()).map(PathBuf::from),
+            allow_guests: read_var("ALLOW_GUESTS").unwrap_or(default.allow_guests),
+            tls_cert: env::var("TLS_CERT").ok().filter(|path| !path.trim().is_empty()).map(PathBuf::from),
+            tls_key: env::var("TLS_KEY").ok().filter(|path| !path.trim().is_empty()).map(PathBuf::from),
+            late_join: read_var("LATE_JOIN").unwrap_or(default.late_join),
+        }
+    }
+}
+
+// Lit les indices supplémentaires : HINTS s'applique à toutes les difficultés, HINTS_<DIFFICULTÉ> le remplace pour une difficulté.
+fn read_hints() -> HashMap<Difficulty, Vec<HintKind>> {
+    let common = read_hint_var("HINTS").unwrap_or_default();
+    [(Difficulty::Easy, "HINTS_EASY"), (Difficulty::Medium, "HINTS_MEDIUM"), (Difficulty::Hard, "HINTS_HARD")]
+        .into_iter()
+        .map(|(difficulty, name)| (difficulty, read_hint_var(name).unwrap_or_else(|| common.clone())))
+        .collect()
+}
+
+// Lit une liste d'indices depuis une variable d'environnement, en ignorant les valeurs invalides.
+fn read_hint_var(name: &str) -> Option<Vec<HintKind>> {
+    let value = env::var(name).ok()?;
+    match parse_hint_kinds(&value) {
+        Ok(kinds) => Some(kinds),
+        Err(error) => {
+            eprintln!("Invalid value for {}: {}", name, error);
+            None
+        }
+    }
+}
+
+// Lit et convertit une variable d'environnement, en ignorant les valeurs invalides.
+fn read_var<T: FromStr>(name: &str) -> Option<T> {
+    match env::var(name) {
+        Ok(value) => match value.trim().parse() {
+            Ok(parsed) => Some(parsed),
+            Err(_) => {
+                eprintln!("Invalid value for {}: {}", name, value);
+                None
+            }
+        },
+        Err(_) => None,
+    }
+}
@@ -0,0 +1,295 @@
+use std::io;
+use std::sync::Mutex;
+use std::time::Duration;
+use crossbeam::channel::{Receiver, RecvTimeoutError, Sender};
+use crate::i18n::parse_difficulty;
+use crate::protocol::{AdminCommand, AdminError, GamePhase, ServerMessage};
+use crate::server::admin;
+use crate::server::game::{Game, GameMode};
+use crate::server::shutdown::ShutdownSignal;
+use crate::server::util::{broadcast_message, Connection};
+
+// Commandes disponibles dans la console d'administration, avec leur syntaxe (utilisée pour l'aide et les erreurs d'usage).
+pub const COMMANDS: [(&str, &str); 13] = [
+    ("rooms", "rooms"),
+    ("players", "players"),
+    ("kick", "kick <player>"),
+    ("ban", "ban <player|ip>"),
+    ("forcestart", "forcestart"),
+    ("skipvote", "skipvote"),
+    ("endround", "endround"),
+    ("difficulty", "difficulty <easy|medium|hard>"),
+    ("host", "host <player>"),
+    ("announce", "announce <message>"),
+    ("reveal", "reveal"),
+    ("shutdown", "shutdown [reason]"),
+    ("help", "help"),
+];
+
+// Enumération représentant une commande tapée dans la console d'administration du serveur.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConsoleCommand {
+    Rooms, // `rooms` : affiche l'état de la partie.
+    Players, // `players` : affiche les clients connectés avec leur adresse.
+    Admin(AdminCommand), // Commandes partagées avec l'hôte (`kick`, `ban`, `forcestart`...).
+    Announce(String), // `announce <message>` : envoie une annonce à tous les clients.
+    Reveal, // `reveal` : affiche le nombre secret de la manche en cours (pour le débogage).
+    Shutdown(Option<String>), // `shutdown [raison]` : arrête le serveur en douceur.
+    Help, // `help` : affiche la liste des commandes.
+}
+
+// Analyse une ligne tapée dans la console. Renvoie None pour une ligne vide, et le message d'erreur à afficher
+// si la commande est inconnue ou mal utilisée.
+pub fn parse(input: &str) -> Option<Result<ConsoleCommand, String>> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+    let (name, argument) = match input.split_once(char::is_whitespace) {
+        Some((name, argument)) => (name.to_lowercase(), argument.trim()),
+        None => (input.to_lowercase(), ""),
+    };
+    let command = match (name.as_str(), argument) {
+        ("rooms", "") => ConsoleCommand::Rooms,
+        ("players", "") => ConsoleCommand::Players,
+        ("kick", player) if !player.is_empty() => ConsoleCommand::Admin(AdminCommand::Kick(player.to_string())),
+        ("ban", target) if !target.is_empty() => ConsoleCommand::Admin(AdminCommand::Ban(target.to_string())),
+        ("forcestart", "") => ConsoleCommand::Admin(AdminCommand::ForceStart),
+        ("skipvote", "") => ConsoleCommand::Admin(AdminCommand::SkipVoting),
+        ("endround", "") => ConsoleCommand::Admin(AdminCommand::EndRound),
+        ("difficulty", level) if !level.is_empty() => match parse_difficulty(level) {
+            Some(difficulty) => ConsoleCommand::Admin(AdminCommand::SetDifficulty(difficulty)),
+            None => return Some(Err(format!("Unknown difficulty: {}", level))),
+        },
+        ("host", player) if !player.is_empty() => ConsoleCommand::Admin(AdminCommand::TransferHost(player.to_string())),
+        ("announce", text) if !text.is_empty() => ConsoleCommand::Announce(text.to_string()),
+        ("reveal", "") => ConsoleCommand::Reveal,
+        ("shutdown", reason) => ConsoleCommand::Shutdown(Some(reason.to_string()).filter(|reason| !reason.is_empty())),
+        ("help", "") => ConsoleCommand::Help,
+        (name, _) => return Some(Err(match COMMANDS.iter().find(|(command, _)| *command == name) {
+            Some((_, usage)) => format!("Usage: {}", usage),
+            None => format!("Unknown command: {} (type help)", name),
+        })),
+    };
+    Some(Ok(command))
+}
+
+// Transmet à la console les lignes tapées sur l'entrée standard, jusqu'à la fin de l'entrée ou l'arrêt du serveur.
+// L'attente d'une ligne est limitée (avec `poll`) pour que ce thread voie l'arrêt du serveur et puisse être attendu.
+#[cfg(unix)]
+pub fn read_stdin(sender: Sender<String>, signal: &ShutdownSignal) {
+    read_lines(libc::STDIN_FILENO, sender, signal);
+}
+
+// Sans `poll`, la lecture de l'entrée standard ne peut pas être interrompue : ce thread n'est pas attendu à l'arrêt du serveur
+// et se termine avec le processus.
+#[cfg(not(unix))]
+pub fn read_stdin(sender: Sender<String>) {
+    use std::io::BufRead;
+    for line in io::stdin().lock().lines() {
+        let Ok(line) = line else { break };
+        if sender.send(line).is_err() {
+            break; // La console s'est arrêtée avec le serveur
+        }
+    }
+}
+
+// Lit les lignes du descripteur de fichier donné sans jamais bloquer plus de 100 ms.
+#[cfg(unix)]
+fn read_lines(fd: std::os::unix::io::RawFd, sender: Sender<String>, signal: &ShutdownSignal) {
+    let mut pending = Vec::new(); // Début de la ligne en cours de saisie
+    let mut buffer = [0u8; 1024];
+    while !signal.is_finished() {
+        let mut input = libc::pollfd { fd, events: libc::POLLIN, revents: 0 };
+        // SAFETY : `input` est une structure `pollfd` valide pendant tout l'appel.
+        let ready = unsafe { libc::poll(&mut input, 1, 100) };
+        if ready == 0 || interrupted(ready as isize) {
+            continue; // Rien à lire : vérifie à nouveau si le serveur s'est arrêté
+        }
+        if ready < 0 {
+            break;
+        }
+        // SAFETY : `buffer` est valide en écriture sur toute sa longueur.
+        let count = unsafe { libc::read(fd, buffer.as_mut_ptr().cast(), buffer.len()) };
+        if interrupted(count) {
+            continue;
+        }
+        if count <= 0 {
+            break; // Fin de l'entrée (ou erreur)
+        }
+        pending.extend_from_slice(&buffer[..count as usize]);
+        while let Some(end) = pending.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<u8> = pending.drain(..=end).collect();
+            if sender.send(String::from_utf8_lossy(&line).trim_end_matches(['\r', '\n']).to_string()).is_err() {
+                return; // La console s'est arrêtée avec le serveur
+            }
+        }
+    }
+    if !pending.is_empty() && !signal.is_finished() {
+        sender.send(String::from_utf8_lossy(&pending).into_owned()).ok(); // Dernière ligne, sans retour à la ligne
+    }
+}
+
+// Indique si un appel système a échoué parce qu'il a été interrompu par un signal (il suffit de le refaire).
+#[cfg(unix)]
+fn interrupted(result: isize) -> bool {
+    result < 0 && io::Error::last_os_error().kind() == io::ErrorKind::Interrupted
+}
+
+// Exécute les lignes tapées dans la console jusqu'à la fin de l'entrée ou jusqu'à l'arrêt du serveur.
+pub fn run(lines: Receiver<String>, game: &Mutex<Game>, clients: &Mutex<Vec<Connection>>, signal: &ShutdownSignal) {
+    while !signal.is_finished() {
+        let line = match lines.recv_timeout(Duration::from_millis(100)) {
+            Ok(line) => line,
+            Err(RecvTimeoutError::Timeout) => continue, // Vérifie régulièrement si le serveur s'est arrêté
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+        match parse(&line) {
+            None => {},
+            Some(Err(error)) => println!("{}", error),
+            Some(Ok(ConsoleCommand::Shutdown(reason))) => {
+                if signal.request(reason) {
+                    println!("Shutdown requested");
+                } else {
+                    println!("The server is already shutting down");
+                }
+            },
+            Some(Ok(command)) => {
+                let mut game = game.lock().unwrap();
+                let clients = clients.lock().unwrap();
+                for line in execute(command, &mut game, &clients) {
+                    println!("{}", line);
+                }
+            },
+        }
+    }
+}
+
+// Exécute une commande de la console (sauf `shutdown`, qui passe par le signal d'arrêt) et renvoie les lignes à afficher.
+pub fn execute(command: ConsoleCommand, game: &mut Game, clients: &[Connection]) -> Vec<String> {
+    match command {
+        ConsoleCommand::Rooms => vec![room(game)],
+        ConsoleCommand::Players => players(game, clients),
+        ConsoleCommand::Admin(command) => match admin::execute(command, None, game, clients) {
+            Ok(()) => vec!["Done".to_string()],
+            Err(error) => vec![admin_error_text(error).to_string()],
+        },
+        ConsoleCommand::Announce(text) => {
+            broadcast_message(ServerMessage::Announcement(text), clients);
+            vec![format!("Announcement sent to {} clients", clients.len())]
+        },
+        ConsoleCommand::Reveal => match game.phase {
+            GamePhase::Playing if game.mode == GameMode::Race => {
+                game.race_secrets().into_iter().map(|(name, secret)| format!("Secret number of {}: {}", name, secret)).collect()
+            },
+            GamePhase::Playing => vec![format!("Secret number: {}", game.secret_number())],
+            _ => vec!["No round in progress".to_string()],
+        },
+        ConsoleCommand::Shutdown(_) => vec![],
+        ConsoleCommand::Help => COMMANDS.iter().map(|(_, usage)| format!("  {}", usage)).collect(),
+    }
+}
+
+// Décrit la partie hébergée par le serveur (un serveur n'héberge qu'une seule partie).
+fn room(game: &Game) -> String {
+    format!(
+        "main: {:?}, round {}/{}, {} players, {} spectators, host: {}",
+        game.phase,
+        game.round,
+        game.rounds_per_match,
+        game.players.len(),
+        game.spectator_names().len(),
+        game.lobby.host().unwrap_or("none"),
+    )
+}
+
+// Décrit les clients connectés : nom, adresse, rôle et score.
+fn players(game: &Game, clients: &[Connection]) -> Vec<String> {
+    if clients.is_empty() {
+        return vec!["No clients connected".to_string()];
+    }
+    clients.iter().map(|client| {
+        let address = client.address.map_or("unknown address".to_string(), |address| address.to_string());
+        let role = if game.is_waiting(&client.name) {
+            "waiting for the next round".to_string()
+        } else if game.is_spectator(&client.name) {
+            "spectator".to_string()
+        } else {
+            let score = game.players.get(&client.name).map_or(0, |player| player.score);
+            format!("player, score {}", score)
+        };
+        let host = if game.lobby.host() == Some(client.name.as_str()) { ", host" } else { "" };
+        format!("{} ({}): {}{}", client.name, address, role, host)
+    }).collect()
+}
+
+// Renvoie le message expliquant le refus d'une commande d'administration.
+fn admin_error_text(error: AdminError) -> &'static str {
+    match error {
+        AdminError::NotHost => "Only the host can manage the game",
+        AdminError::WrongPhase => "This command is not available in the current phase",
+        AdminError::UnknownPlayer => "That player is not in the game",
+        AdminError::OwnPlayer => "You cannot kick or ban yourself",
+        AdminError::NoPlayers => "There are no players to start the match",
+    }
+}
+
+// Tests unitaires pour le module console
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::Difficulty;
+    use crossbeam::channel::unbounded;
+
+    // Test pour la lecture des lignes, qui s'arrête à la fin de l'entrée ou à l'arrêt du serveur
+    #[cfg(unix)]
+    #[test]
+    fn test_read_lines() {
+        use std::io::Write;
+        use std::os::unix::io::AsRawFd;
+
+        let (reader, mut writer) = io::pipe().unwrap();
+        let (sender, lines) = unbounded();
+        writer.write_all(b"rooms\r\nkick Bob\nhel").unwrap();
+        drop(writer);
+        read_lines(reader.as_raw_fd(), sender, &ShutdownSignal::new());
+        assert_eq!(lines.try_iter().collect::<Vec<_>>(), vec!["rooms", "kick Bob", "hel"]);
+
+        // Rien n'est tapé, mais la lecture s'arrête avec le serveur.
+        let (reader, _writer) = io::pipe().unwrap();
+        let (sender, lines) = unbounded();
+        let signal = ShutdownSignal::new();
+        signal.finish();
+        read_lines(reader.as_raw_fd(), sender, &signal);
+        assert!(lines.is_empty());
+    }
+
+    // Test pour l'analyse des commandes de la console
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("  "), None);
+        assert_eq!(parse("players"), Some(Ok(ConsoleCommand::Players)));
+        assert_eq!(parse("KICK Bob"), Some(Ok(ConsoleCommand::Admin(AdminCommand::Kick("Bob".to_string())))));
+        assert_eq!(parse("difficulty hard"), Some(Ok(ConsoleCommand::Admin(AdminCommand::SetDifficulty(Difficulty::Hard)))));
+        assert_eq!(parse("announce Back in  5 minutes"), Some(Ok(ConsoleCommand::Announce("Back in  5 minutes".to_string()))));
+        assert_eq!(parse("kick"), Some(Err("Usage: kick <player>".to_string())));
+        assert_eq!(parse("reveal now"), Some(Err("Usage: reveal".to_string())));
+        assert_eq!(parse("shutdown"), Some(Ok(ConsoleCommand::Shutdown(None))));
+        assert_eq!(parse("shutdown back soon"), Some(Ok(ConsoleCommand::Shutdown(Some("back soon".to_string())))));
+        assert_eq!(parse("dance"), Some(Err("Unknown command: dance (type help)".to_string())));
+    }
+
+    // Test pour l'exécution des commandes de la console
+    #[test]
+    fn test_execute() {
+        let mut game = Game::new();
+        game.add_player("Alice".to_string());
+        assert_eq!(execute(ConsoleCommand::Rooms, &mut game, &[]), vec!["main: Identification, round 1/1, 1 players, 0 spectators, host: Alice".to_string()]);
+        assert_eq!(execute(ConsoleCommand::Players, &mut game, &[]), vec!["No clients connected".to_string()]);
+        assert_eq!(execute(ConsoleCommand::Reveal, &mut game, &[]), vec!["No round in progress".to_string()]);
+        assert_eq!(execute(ConsoleCommand::Admin(AdminCommand::SkipVoting), &mut game, &[]), vec!["This command is not available in the current phase".to_string()]);
+        assert_eq!(execute(ConsoleCommand::Admin(AdminCommand::ForceStart), &mut game, &[]), vec!["Done".to_string()]);
+        execute(ConsoleCommand::Admin(AdminCommand::SkipVoting), &mut game, &[]);
+        assert_eq!(execute(ConsoleCommand::Reveal, &mut game, &[]), vec![format!("Secret number: {}", game.secret_number())]);
+    }
+}
@@ -0,0 +1,994 @@
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Serialize, Deserialize};
+use std::time::{Instant, Duration};
+use crate::protocol::{Difficulty, GamePhase, GuessError, Hint};
+use crate::server::admin::Bans;
+use crate::server::chat::Chat;
+use crate::server::hint::{HigherLower, HintProvider};
+use crate::server::lobby::{Lobby, LobbyError};
+use crate::server::player::Player;
+
+// Enumération représentant les modes de jeu disponibles.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum GameMode {
+    Simultaneous, // Tous les joueurs devinent en même temps, le plus rapide gagne.
+    TurnBased, // Les joueurs devinent chacun leur tour, dans un ordre imposé par le serveur.
+    Race, // Chaque joueur a son propre nombre secret, le premier à trouver le sien gagne.
+}
+
+impl FromStr for GameMode {
+    type Err = String;
+
+    // Permet de lire le mode de jeu depuis la configuration ("simultaneous", "turn" ou "race").
+    fn from_str(value: &str) -> Result<GameMode, String> {
+        match value.to_lowercase().as_str() {
+            "simultaneous" => Ok(GameMode::Simultaneous),
+            "turn" | "turn-based" | "turn_based" => Ok(GameMode::TurnBased),
+            "race" => Ok(GameMode::Race),
+            _ => Err(format!("unknown game mode: {}", value)),
+        }
+    }
+}
+
+// Enumération représentant ce que les autres joueurs voient des devinettes d'un joueur.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum HintVisibility {
+    Private, // Les autres joueurs ne voient ni la devinette ni l'indice.
+    GuessOnly, // Les autres joueurs voient la devinette mais pas l'indice.
+    Shared, // Les autres joueurs voient la devinette et l'indice reçu.
+}
+
+impl FromStr for HintVisibility {
+    type Err = String;
+
+    // Permet de lire la visibilité des indices depuis la configuration ("private", "guess" ou "shared").
+    fn from_str(value: &str) -> Result<HintVisibility, String> {
+        match value.to_lowercase().as_str() {
+            "private" => Ok(HintVisibility::Private),
+            "guess" | "guess-only" | "guess_only" => Ok(HintVisibility::GuessOnly),
+            "shared" => Ok(HintVisibility::Shared),
+            _ => Err(format!("unknown hint visibility: {}", value)),
+        }
+    }
+}
+
+// Enumération représentant ce qui arrive à un joueur qui rejoint la partie pendant une manche.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, Hash, PartialEq)]
+pub enum LateJoinPolicy {
+    Immediate, // Le joueur participe tout de suite à la manche en cours.
+    Queue, // Le joueur attend la manche suivante.
+    Spectate, // Le joueur regarde la manche en cours en spectateur, puis joue à partir de la manche suivante.
+}
+
+impl FromStr for LateJoinPolicy {
+    type Err = String;
+
+    // Permet de lire la politique d'arrivée en cours de manche depuis la configuration ("immediate", "queue" ou "spectate").
+    fn from_str(value: &str) -> Result<LateJoinPolicy, String> {
+        match value.to_lowercase().as_str() {
+            "immediate" => Ok(LateJoinPolicy::Immediate),
+            "queue" => Ok(LateJoinPolicy::Queue),
+            "spectate" => Ok(LateJoinPolicy::Spectate),
+            _ => Err(format!("unknown late join policy: {}", value)),
+        }
+    }
+}
+
+// Structure représentant l'état du jeu.
+pub struct Game {
+    pub players: HashMap<String, Player>, // Liste des joueurs et leurs scores cumulés sur le match.
+    spectators: BTreeSet<String>, // Spectateurs : ils suivent la partie sans voter ni deviner, et ne comptent pas dans le match.
+    waiting: BTreeSet<String>, // Joueurs arrivés pendant une manche, qui rejoindront la partie à la manche suivante.
+    secret_number: u32, // Nombre secret que les joueurs doivent deviner.
+    pub difficulty: Difficulty, // Niveau de difficulté actuel du jeu.
+    high_scores: Vec<(String, u32)>, // Liste des meilleurs scores.
+    difficulty_votes: HashMap<Difficulty, u32>, // Votes pour déterminer la difficulté.
+    forced_difficulty: Option<Difficulty>, // Difficulté imposée par l'hôte pour la prochaine manche (remplace les votes).
+    start_time: Option<Instant>, // Instant où le jeu a commencé (utilisé pour gérer le timing des phases).
+    pub phase: GamePhase, // Phase actuelle du jeu.
+    voting_duration: Duration, // Durée de la phase de vote.
+    round_duration: Option<Duration>, // Durée maximale d'une manche (None = pas de limite).
+    round_start: Option<Instant>, // Instant où la manche en cours a commencé.
+    pub round: u32, // Numéro de la manche en cours (à partir de 1).
+    pub rounds_per_match: u32, // Nombre de manches dans un match.
+    pub mode: GameMode, // Mode de jeu (simultané ou tour par tour).
+    pub hint_visibility: HintVisibility, // Ce que les autres joueurs voient des devinettes.
+    turn_order: Vec<String>, // Ordre de passage des joueurs en mode tour par tour.
+    turn_index: usize, // Position du joueur dont c'est le tour dans `turn_order`.
+    turn_duration: Duration, // Temps accordé à chaque joueur pour deviner en mode tour par tour.
+    turn_start: Option<Instant>, // Instant où le tour en cours a commencé.
+    race_secrets: HashMap<String, u32>, // En mode course, nombre secret propre à chaque joueur.
+    race_depth: u32, // En mode course, profondeur de recherche dichotomique commune à tous les nombres secrets.
+    hint_providers: HashMap<Difficulty, Vec<Box<dyn HintProvider>>>, // Indices supplémentaires donnés selon la difficulté.
+    pub chat: Chat, // Vérification des messages du chat (longueur, débit, filtre).
+    pub spectators_see_secret: bool, // Les spectateurs reçoivent le nombre secret à la fin d'une manche gagnée.
+    pub late_join: LateJoinPolicy, // Ce qui arrive à un joueur qui rejoint la partie pendant une manche.
+    pub lobby: Lobby, // Salon d'attente avant la phase de vote (joueurs prêts, hôte, lancement automatique).
+    pub bans: Bans, // Noms et adresses exclus de la partie jusqu'à l'arrêt du serveur.
+}
+
+impl Default for Game {
+    fn default() -> Game {
+        Game::new()
+    }
+}
+
+impl Game {
+    // Fonction de création d'un nouvel état de jeu.
+    pub fn new() -> Game {
+        Game {
+            players: HashMap::new(), // Initialise la liste des joueurs.
+            spectators: BTreeSet::new(), // Aucun spectateur au départ.
+            waiting: BTreeSet::new(), // Aucun joueur en attente au départ.
+            secret_number: rand::thread_rng().gen_range(1..101), // Génère un nombre secret aléatoire entre 1 et 100.
+            difficulty: Difficulty::Easy, // Définit la difficulté par défaut à "Facile".
+            high_scores: vec![], // Initialise la liste des meilleurs scores.
+            difficulty_votes: HashMap::new(), // Initialise la carte des votes de difficulté.
+            forced_difficulty: None, // La difficulté est choisie par les votes par défaut.
+            start_time: None, // Aucun moment de début tant que le jeu n'a pas commencé.
+            phase: GamePhase::Identification, // Le jeu commence dans la phase d'identification.
+            voting_duration: Duration::new(20, 0), // 20 secondes de vote par défaut.
+            round_duration: None, // Pas de limite de temps par défaut.
+            round_start: None, // Aucune manche en cours.
+            round: 1, // Le match commence à la première manche.
+            rounds_per_match: 1, // Une seule manche par match par défaut.
+            mode: GameMode::Simultaneous, // Mode simultané par défaut.
+            hint_visibility: HintVisibility::GuessOnly, // Les devinettes sont partagées, mais pas les indices.
+            turn_order: vec![], // Aucun ordre de passage tant que la manche n'a pas commencé.
+            turn_index: 0,
+            turn_duration: Duration::new(15, 0), // 15 secondes par tour par défaut.
+            turn_start: None, // Aucun tour en cours.
+            race_secrets: HashMap::new(), // Aucun nombre secret individuel tant que la manche n'a pas commencé.
+            race_depth: 0,
+            hint_providers: HashMap::new(), // Seul l'indice "plus/moins" est donné par défaut.
+            chat: Chat::default(), // Limites du chat par défaut, sans filtre.
+            spectators_see_secret: true,
+            late_join: LateJoinPolicy::Immediate, // Par défaut, un joueur en retard participe tout de suite.
+            lobby: Lobby::new(false, 1, Duration::new(10, 0)), // Sans salon par défaut : le vote démarre dès l'arrivée d'un joueur.
+            bans: Bans::default(), // Personne n'est exclu au départ.
+        }
+    }
+
+    // Définit les indices supplémentaires donnés après chaque devinette pour un niveau de difficulté.
+    pub fn set_hint_providers(&mut self, difficulty: Difficulty, providers: Vec<Box<dyn HintProvider>>) {
+        self.hint_providers.insert(difficulty, providers);
+    }
+
+    // Configure le mode de jeu et le temps accordé à chaque tour en mode tour par tour.
+    pub fn set_mode(&mut self, mode: GameMode, turn_duration: Duration) {
+        self.mode = mode;
+        self.turn_duration = turn_duration;
+    }
+
+    // Configure la durée de la phase de vote et la durée maximale d'une manche.
+    pub fn set_timers(&mut self, voting_duration: Duration, round_duration: Option<Duration>) {
+        self.voting_duration = voting_duration;
+        self.round_duration = round_duration;
+    }
+
+    // Ajoute un joueur au jeu.
+    // Pendant une manche, la politique d'arrivée en cours de manche peut le faire attendre la manche suivante.
+    pub fn add_player(&mut self, name: String) {
+        if self.phase == GamePhase::Playing && self.late_join != LateJoinPolicy::Immediate {
+            if self.late_join == LateJoinPolicy::Spectate {
+                self.spectators.insert(name.clone()); // Il regarde la manche en cours en attendant.
+            }
+            self.waiting.insert(name);
+            return;
+        }
+        if self.phase == GamePhase::Playing && self.mode == GameMode::TurnBased && !self.turn_order.contains(&name) {
+            self.turn_order.push(name.clone()); // Un joueur arrivant en cours de manche passe en dernier.
+        }
+        if self.phase == GamePhase::Playing && self.mode == GameMode::Race {
+            // Un joueur arrivant en cours de course reçoit un nombre aussi difficile à trouver que celui des autres.
+            let taken: Vec<u32> = self.race_secrets.values().copied().collect();
+            let secret = draw_secret_with_depth(self.difficulty.max_number(), self.race_depth, &taken);
+            self.race_secrets.insert(name.clone(), secret);
+        }
+        self.lobby.join(&name);
+        self.players.insert(name.clone(), Player::new(name)); // Le score initial du joueur est de 0.
+    }
+
+    // Ajoute un spectateur : il n'est pas compté parmi les joueurs du match.
+    pub fn add_spectator(&mut self, name: String) {
+        self.spectators.insert(name);
+    }
+
+    // Indique si le joueur attend la manche suivante pour rejoindre la partie.
+    pub fn is_waiting(&self, name: &str) -> bool {
+        self.waiting.contains(name)
+    }
+
+    // Indique si des joueurs attendent la manche suivante.
+    pub fn has_waiting(&self) -> bool {
+        !self.waiting.is_empty()
+    }
+
+    // Renvoie les noms des joueurs qui attendent la manche suivante, triés par ordre alphabétique.
+    pub fn waiting_names(&self) -> Vec<String> {
+        self.waiting.iter().cloned().collect()
+    }
+
+    // Fait entrer dans la partie les joueurs arrivés pendant la manche.
+    fn admit_waiting(&mut self) {
+        for name in std::mem::take(&mut self.waiting) {
+            self.spectators.remove(&name);
+            self.lobby.join(&name);
+            self.players.insert(name.clone(), Player::new(name));
+        }
+    }
+
+    // Indique si le client portant ce nom est un spectateur.
+    pub fn is_spectator(&self, name: &str) -> bool {
+        self.spectators.contains(name)
+    }
+
+    // Renvoie les noms des spectateurs, triés par ordre alphabétique.
+    pub fn spectator_names(&self) -> Vec<String> {
+        self.spectators.iter().cloned().collect()
+    }
+
+    // Retire un joueur (ou un spectateur) du jeu.
+    pub fn remove_player(&mut self, name: &str) {
+        self.players.remove(name); // Retire le joueur de la liste des joueurs.
+        self.spectators.remove(name);
+        self.waiting.remove(name);
+        self.lobby.leave(name);
+        self.race_secrets.remove(name);
+        if let Some(position) = self.turn_order.iter().position(|player| player == name) {
+            self.turn_order.remove(position); // Retire le joueur de l'ordre de passage.
+            if position < self.turn_index {
+                self.turn_index -= 1; // Le joueur courant recule d'une place dans l'ordre.
+            } else if position == self.turn_index {
+                self.turn_start = Some(Instant::now()); // Le joueur suivant prend la main avec un tour complet.
+            }
+            if self.turn_index >= self.turn_order.len() {
+                self.turn_index = 0;
+            }
+        }
+    }
+    
+    // Renvoie les noms des joueurs, triés par ordre alphabétique.
+    pub fn player_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.players.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    // Gère la tentative de devinette d'un joueur.
+    // Renvoie l'indice "plus/moins" (ou la victoire) suivi des indices supplémentaires configurés pour la difficulté.
+    pub fn guess(&mut self, player_name: &str, guess: u32) -> Result<Vec<Hint>, GuessError> {
+        if self.phase != GamePhase::Playing { // Les devinettes ne sont acceptées que pendant la phase de jeu.
+            return Err(GuessError::WrongPhase);
+        }
+        if self.is_waiting(player_name) { // Un joueur arrivé pendant la manche attend la suivante.
+            return Err(GuessError::NextRound);
+        }
+        if self.is_spectator(player_name) { // Les spectateurs regardent la partie sans y participer.
+            return Err(GuessError::Spectating);
+        }
+        if self.mode == GameMode::TurnBased && self.current_turn() != Some(player_name) { // En mode tour par tour, seul le joueur courant peut deviner.
+            return Err(GuessError::NotYourTurn);
+        }
+        let secret_number = self.secret_for(player_name);
+        if let Some(player) = self.players.get_mut(player_name) {
+            player.increment_attempts(); // Compte les tentatives du joueur pendant la manche.
+        }
+        let max = self.difficulty.max_number();
+        let hint = HigherLower::compare(secret_number, guess);
+        if hint == Hint::Correct { // Si la devinette est correcte.
+            if let Some(player) = self.players.get_mut(player_name) {
+                player.increment_score(); // Le gagnant de la manche marque un point.
+            }
+            self.high_scores.push((player_name.to_string(), guess)); // Ajoute le joueur à la liste des meilleurs scores.
+            return Ok(vec![hint]); // Pas besoin d'indices supplémentaires en cas de victoire.
+        }
+        let mut hints = vec![hint];
+        if let Some(providers) = self.hint_providers.get(&self.difficulty) {
+            hints.extend(providers.iter().filter_map(|provider| provider.hint(secret_number, guess, max)));
+        }
+        Ok(hints) // Retourne les indices.
+    }
+
+    // Renvoie le nombre secret que le joueur doit deviner (le sien en mode course, le nombre commun sinon).
+    fn secret_for(&self, player_name: &str) -> u32 {
+        match self.race_secrets.get(player_name) {
+            Some(&secret) if self.mode == GameMode::Race => secret,
+            _ => self.secret_number,
+        }
+    }
+
+    // Renvoie le nombre de tentatives d'un joueur pendant la manche en cours.
+    pub fn attempts(&self, player_name: &str) -> u32 {
+        self.players.get(player_name).map_or(0, |player| player.attempts)
+    }
+
+    // Définit la difficulté du jeu et ajuste la plage du nombre secret en conséquence.
+    pub fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+        self.secret_number = rand::thread_rng().gen_range(1..=difficulty.max_number());
+    }
+
+    // Permet aux joueurs de voter pour la difficulté.
+    pub fn vote_difficulty(&mut self, difficulty: Difficulty) {
+        let count = self.difficulty_votes.entry(difficulty).or_insert(0); // Incrémente le nombre de votes pour la difficulté choisie.
+        *count += 1;
+    }
+
+    // Impose la difficulté de la prochaine manche, quels que soient les votes.
+    pub fn force_difficulty(&mut self, difficulty: Difficulty) {
+        self.forced_difficulty = Some(difficulty);
+    }
+
+    // Détermine la difficulté finale basée sur les votes des joueurs (ou imposée par l'hôte).
+    pub fn determine_difficulty(&mut self) {
+        if let Some(difficulty) = self.forced_difficulty.take() {
+            self.set_difficulty(difficulty);
+            return;
+        }
+        let mut max_votes = 0;
+        let mut selected_difficulty = Difficulty::Easy;
+
+        for (&difficulty, &votes) in &self.difficulty_votes {
+            if votes > max_votes { // Sélectionne la difficulté avec le plus de votes.
+                max_votes = votes;
+                selected_difficulty = difficulty;
+            } else if votes == max_votes { // En cas d'égalité, sélection aléatoire entre les options en tête.
+                if rand::thread_rng().gen_bool(0.5) {
+                    selected_difficulty = difficulty;
+                }
+            }
+        }
+
+        self.set_difficulty(selected_difficulty); // Applique la difficulté déterminée.
+    }
+
+    // Démarre la phase de vote.
+    pub fn start_voting_phase(&mut self) {
+        self.phase = GamePhase::Voting; // Passe à la phase de vote.
+        self.start_time = Some(Instant::now()); // Enregistre le moment de début de cette phase.
+        self.lobby.reset(); // Les joueurs devront se déclarer prêts de nouveau au prochain passage dans le salon.
+    }
+
+    // Indique si la partie attend dans le salon que les joueurs soient prêts.
+    pub fn in_lobby(&self) -> bool {
+        self.lobby.enabled && self.phase == GamePhase::Identification
+    }
+
+    // Change l'état "prêt" d'un joueur dans le salon et renvoie son nouvel état.
+    pub fn toggle_ready(&mut self, name: &str) -> Result<bool, LobbyError> {
+        if self.is_spectator(name) {
+            return Err(LobbyError::Spectating);
+        }
+        if !self.in_lobby() {
+            return Err(LobbyError::NotInLobby);
+        }
+        Ok(self.lobby.toggle_ready(name))
+    }
+
+    // L'hôte lance le match sans attendre que tous les joueurs soient prêts : la phase de vote commence.
+    pub fn start_match(&mut self, name: &str) -> Result<(), LobbyError> {
+        if !self.in_lobby() {
+            return Err(LobbyError::NotInLobby);
+        }
+        self.lobby.can_start(name)?;
+        self.start_voting_phase();
+        Ok(())
+    }
+
+    // Vérifie si le compte à rebours du salon est terminé (tous les joueurs sont prêts depuis assez longtemps).
+    pub fn check_lobby_countdown(&self) -> bool {
+        self.in_lobby() && self.lobby.countdown_remaining() == Some(0)
+    }
+
+    // Vérifie si la phase de vote est terminée (basée sur le countdown).
+    pub fn check_voting_phase(&self) -> bool {
+        if let Some(start_time) = self.start_time {
+            return start_time.elapsed() >= self.voting_duration; // Vérifie si la durée du vote s'est écoulée depuis le début de la phase de vote.
+        }
+        false
+    }
+
+    // Renvoie le temps restant (en secondes, arrondi au supérieur) avant la fin de la phase de vote.
+    pub fn voting_time_remaining(&self) -> u32 {
+        match self.start_time {
+            Some(start_time) => seconds_left(self.voting_duration, start_time),
+            None => 0,
+        }
+    }
+
+    // Démarre la phase de jeu après le vote.
+    pub fn start_game_phase(&mut self) {
+        self.phase = GamePhase::Playing; // Passe à la phase de jeu.
+        self.start_time = None; // Réinitialise le temps de début.
+        self.round_start = Some(Instant::now()); // Démarre le minuteur de la manche.
+        for player in self.players.values_mut() {
+            player.attempts = 0; // Les tentatives sont comptées manche par manche.
+        }
+        if self.mode == GameMode::Race {
+            self.draw_race_secrets();
+        }
+        if self.mode == GameMode::TurnBased {
+            // Tire au sort l'ordre de passage des joueurs pour cette manche.
+            self.turn_order = self.players.keys().cloned().collect();
+            self.turn_order.shuffle(&mut rand::thread_rng());
+            self.turn_index = 0;
+            self.turn_start = Some(Instant::now());
+        }
+    }
+
+    // Tire un nombre secret par joueur pour le mode course.
+    // Par équité, tous les nombres demandent le même nombre d'étapes pour être trouvés par une recherche dichotomique optimale.
+    fn draw_race_secrets(&mut self) {
+        let max = self.difficulty.max_number();
+        self.race_depth = race_depth(max);
+        self.race_secrets.clear();
+        for name in self.players.keys() {
+            let taken: Vec<u32> = self.race_secrets.values().copied().collect();
+            self.race_secrets.insert(name.clone(), draw_secret_with_depth(max, self.race_depth, &taken));
+        }
+    }
+
+    // Renvoie les nombres secrets de chaque joueur en mode course (pour les révéler à la fin de la manche).
+    pub fn race_secrets(&self) -> Vec<(String, u32)> {
+        let mut secrets: Vec<(String, u32)> = self.race_secrets.iter().map(|(name, &secret)| (name.clone(), secret)).collect();
+        secrets.sort();
+        secrets
+    }
+
+    // Renvoie le nom du joueur dont c'est le tour (None hors du mode tour par tour ou hors manche).
+    pub fn current_turn(&self) -> Option<&str> {
+        if self.mode != GameMode::TurnBased || self.phase != GamePhase::Playing {
+            return None;
+        }
+        self.turn_order.get(self.turn_index).map(|name| name.as_str())
+    }
+
+    // Passe la main au joueur suivant et renvoie son nom.
+    pub fn advance_turn(&mut self) -> Option<&str> {
+        if self.turn_order.is_empty() {
+            return None;
+        }
+        self.turn_index = (self.turn_index + 1) % self.turn_order.len();
+        self.turn_start = Some(Instant::now());
+        self.current_turn()
+    }
+
+    // Vérifie si le joueur courant a dépassé le temps accordé pour son tour.
+    pub fn check_turn_timeout(&self) -> bool {
+        match self.turn_start {
+            Some(turn_start) => self.current_turn().is_some() && turn_start.elapsed() >= self.turn_duration,
+            None => false,
+        }
+    }
+
+    // Renvoie la durée maximale d'une manche en secondes (None si la manche n'est pas limitée dans le temps).
+    pub fn round_duration_secs(&self) -> Option<u32> {
+        self.round_duration.map(|duration| duration.as_secs() as u32)
+    }
+
+    // Renvoie le temps restant (en secondes, arrondi au supérieur) avant la fin de la manche en cours.
+    pub fn round_time_remaining(&self) -> Option<u32> {
+        match (self.round_duration, self.round_start) {
+            (Some(duration), Some(round_start)) if self.phase == GamePhase::Playing => Some(seconds_left(duration, round_start)),
+            _ => None,
+        }
+    }
+
+    // Renvoie le temps restant (en secondes) de la phase en cours : vote ou manche limitée dans le temps.
+    pub fn phase_time_remaining(&self) -> Option<u32> {
+        match self.phase {
+            GamePhase::Voting => Some(self.voting_time_remaining()),
+            GamePhase::Playing => self.round_time_remaining(),
+            GamePhase::Identification => None,
+        }
+    }
+
+    // Vérifie si le temps imparti pour la manche en cours est écoulé.
+    pub fn check_round_timeout(&self) -> bool {
+        self.round_time_remaining() == Some(0)
+    }
+
+    // Renvoie le nombre secret de la manche en cours (pour le révéler quand le temps est écoulé, ou depuis la console).
+    pub fn secret_number(&self) -> u32 {
+        self.secret_number
+    }
+
+    // Termine la manche en cours. Renvoie `true` si c'était la dernière manche du match.
+    // Sinon, les joueurs restent connectés et une nouvelle phase de vote commence pour la manche suivante.
+    // Les joueurs arrivés pendant la manche entrent dans la partie.
+    pub fn end_round(&mut self) -> bool {
+        self.admit_waiting();
+        self.round_start = None; // Arrête le minuteur de la manche.
+        self.turn_order.clear(); // L'ordre de passage est tiré à nouveau à chaque manche.
+        self.turn_start = None;
+        self.race_secrets.clear(); // Les nombres secrets de la course sont tirés à nouveau à chaque manche.
+        self.difficulty_votes.clear(); // Les votes sont propres à chaque manche.
+        self.forced_difficulty = None;
+        if self.round >= self.rounds_per_match {
+            return true;
+        }
+        self.round += 1;
+        self.restart();
+        false
+    }
+
+    // Renvoie le classement du match : les joueurs triés par score décroissant (puis par nom).
+    pub fn standings(&self) -> Vec<(String, u32)> {
+        let mut standings: Vec<(String, u32)> = self.players.values().map(|player| (player.name.clone(), player.score)).collect();
+        standings.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        standings
+    }
+
+    // Renvoie le vainqueur du match : le joueur ayant le meilleur score, s'il est seul en tête avec au moins un point.
+    pub fn match_winner(&self) -> Option<String> {
+        let standings = self.standings();
+        match standings.as_slice() {
+            [(name, score), rest @ ..] if *score > 0 && rest.first().is_none_or(|(_, next)| next < score) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    // Termine le match et réinitialise l'état : les scores sont remis à zéro mais les joueurs connectés restent dans la partie.
+    pub fn end_game(&mut self) {
+        self.round_start = None; // Arrête le minuteur de la manche.
+        for player in self.players.values_mut() {
+            player.score = 0; // Remet les scores à zéro pour le prochain match.
+        }
+        self.difficulty_votes.clear(); // Vide les votes de difficulté.
+        self.forced_difficulty = None;
+        self.round = 1; // Le prochain match recommence à la première manche.
+        if self.lobby.enabled {
+            // Avec un salon, les joueurs y retournent et se déclarent prêts pour le match suivant.
+            self.start_time = None;
+            self.phase = GamePhase::Identification;
+            self.lobby.reset();
+        } else {
+            self.restart();
+        }
+    }
+
+    // Relance une phase de vote s'il reste des joueurs, sinon revient à la phase d'identification.
+    fn restart(&mut self) {
+        if self.players.is_empty() {
+            self.start_time = None; // Réinitialise le temps de début.
+            self.phase = GamePhase::Identification; // Reviens à la phase d'identification.
+        } else {
+            self.start_voting_phase();
+        }
+    }
+}
+
+// Calcule le nombre d'essais nécessaires pour trouver `secret` dans la plage 1..=max avec une recherche dichotomique optimale.
+pub fn binary_search_depth(secret: u32, max: u32) -> u32 {
+    let (mut low, mut high) = (1, max);
+    let mut depth = 1;
+    loop {
+        let middle = low + (high - low) / 2; // Essai optimal : le milieu de l'intervalle encore possible.
+        if secret == middle || low >= high {
+            return depth;
+        }
+        if secret < middle {
+            high = middle - 1;
+        } else {
+            low = middle + 1;
+        }
+        depth += 1;
+    }
+}
+
+// Choisit la profondeur de recherche commune aux nombres secrets du mode course : celle qui compte le plus de nombres
+// (la plus profonde en cas d'égalité), pour que chaque joueur puisse recevoir un nombre différent.
+fn race_depth(max: u32) -> u32 {
+    let mut counts: HashMap<u32, usize> = HashMap::new();
+    for number in 1..=max {
+        *counts.entry(binary_search_depth(number, max)).or_insert(0) += 1;
+    }
+    counts.into_iter().max_by_key(|&(depth, count)| (count, depth)).map_or(1, |(depth, _)| depth)
+}
+
+// Tire au hasard un nombre de la plage 1..=max qui demande exactement `depth` essais en recherche dichotomique,
+// en évitant si possible les nombres déjà attribués (`taken`).
+fn draw_secret_with_depth(max: u32, depth: u32, taken: &[u32]) -> u32 {
+    let candidates: Vec<u32> = (1..=max).filter(|&number| binary_search_depth(number, max) == depth).collect();
+    let free: Vec<u32> = candidates.iter().copied().filter(|number| !taken.contains(number)).collect();
+    match free.choose(&mut rand::thread_rng()).or_else(|| candidates.choose(&mut rand::thread_rng())) {
+        Some(&secret) => secret,
+        None => rand::thread_rng().gen_range(1..=max), // Profondeur impossible : on retombe sur un tirage classique.
+    }
+}
+
+// Calcule le nombre de secondes restantes (arrondi au supérieur) avant l'expiration d'une durée démarrée à `start`.
+fn seconds_left(duration: Duration, start: Instant) -> u32 {
+    let remaining = duration.saturating_sub(start.elapsed());
+    let secs = remaining.as_secs() as u32;
+    if remaining.subsec_nanos() > 0 { secs + 1 } else { secs }
+}
+
+
+//Tests unitaires
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::hint::HintKind;
+
+    // Test pour la création d'un nouveau jeu avec la difficulté par défaut
+    #[test]
+    fn test_game_new() {
+        let game = Game::new();
+        assert_eq!(game.difficulty, Difficulty::Easy);
+        assert_eq!(game.players.len(), 0);
+        assert_eq!(game.phase, GamePhase::Identification);
+    }
+
+    // Test pour ajouter un joueur
+    #[test]
+    fn test_add_player() {
+        let mut game = Game::new();
+        game.add_player("Player1".to_string());
+        assert_eq!(game.players.len(), 1);
+        assert_eq!(game.players.get("Player1").unwrap().score, 0);
+    }
+
+    // Test pour enlever un joueur
+    #[test]
+    fn test_remove_player() {
+        let mut game = Game::new();
+        game.add_player("Player1".to_string());
+        game.remove_player("Player1");
+        assert!(!game.players.contains_key("Player1"));
+    }
+
+    // Test pour le salon d'attente : prêt, lancement par l'hôte et retour au salon à la fin du match
+    #[test]
+    fn test_lobby() {
+        let mut game = Game::new();
+        game.lobby = Lobby::new(true, 2, Duration::new(0, 0));
+        game.add_player("Alice".to_string());
+        game.add_spectator("Zoe".to_string());
+        assert!(game.in_lobby());
+        assert_eq!(game.toggle_ready("Zoe"), Err(LobbyError::Spectating));
+        assert_eq!(game.start_match("Alice"), Err(LobbyError::NotEnoughPlayers));
+        game.add_player("Bob".to_string());
+        assert_eq!(game.start_match("Bob"), Err(LobbyError::NotHost));
+        assert_eq!(game.toggle_ready("Alice"), Ok(true));
+        assert!(!game.check_lobby_countdown());
+        assert_eq!(game.toggle_ready("Bob"), Ok(true));
+        assert!(game.check_lobby_countdown()); // Délai nul : le match peut commencer.
+
+        assert_eq!(game.start_match("Alice"), Ok(()));
+        assert_eq!(game.phase, GamePhase::Voting);
+        assert_eq!(game.toggle_ready("Alice"), Err(LobbyError::NotInLobby));
+        assert!(game.lobby.ready_names().is_empty());
+        game.end_game();
+        assert!(game.in_lobby());
+    }
+
+    // Test pour les politiques d'arrivée en cours de manche
+    #[test]
+    fn test_late_join() {
+        let mut game = Game::new();
+        game.add_player("Alice".to_string());
+        game.late_join = LateJoinPolicy::Queue;
+        game.add_player("Bob".to_string()); // Hors manche, la politique ne s'applique pas.
+        game.start_game_phase();
+        game.add_player("Carol".to_string());
+        game.late_join = LateJoinPolicy::Spectate;
+        game.add_player("Dave".to_string());
+        assert_eq!(game.player_names(), vec!["Alice".to_string(), "Bob".to_string()]);
+        assert_eq!(game.spectator_names(), vec!["Dave".to_string()]);
+        assert_eq!(game.waiting_names(), vec!["Carol".to_string(), "Dave".to_string()]);
+        assert_eq!(game.guess("Carol", 50), Err(GuessError::NextRound));
+        assert_eq!(game.guess("Dave", 50), Err(GuessError::NextRound));
+
+        game.rounds_per_match = 2;
+        game.end_round();
+        assert!(!game.has_waiting());
+        assert_eq!(game.player_names().len(), 4);
+        assert!(game.spectator_names().is_empty());
+    }
+
+    // Test pour les spectateurs : ils ne sont pas comptés parmi les joueurs et ne peuvent pas deviner
+    #[test]
+    fn test_spectators() {
+        let mut game = Game::new();
+        game.add_player("Alice".to_string());
+        game.add_spectator("Zoe".to_string());
+        game.add_spectator("Bob".to_string());
+        assert_eq!(game.player_names(), vec!["Alice".to_string()]);
+        assert_eq!(game.spectator_names(), vec!["Bob".to_string(), "Zoe".to_string()]);
+        game.start_game_phase();
+        assert_eq!(game.guess("Zoe", 50), Err(GuessError::Spectating));
+        assert!(game.standings().iter().all(|(name, _)| name == "Alice"));
+        game.remove_player("Zoe");
+        assert_eq!(game.spectator_names(), vec!["Bob".to_string()]);
+    }
+
+    // Test pour la devinette (plus)
+    #[test]
+    fn test_guess_more() {
+        let mut game = Game::new();
+        game.secret_number = 50;
+        game.phase = GamePhase::Playing;
+        let result = game.guess("Player1", 40).unwrap();
+        assert_eq!(result, vec![Hint::Higher]);
+    }
+
+    // Test pour la devinette (moins)
+    #[test]
+    fn test_guess_less() {
+        let mut game = Game::new();
+        game.secret_number = 50;
+        game.phase = GamePhase::Playing;
+        let result = game.guess("Player1", 60).unwrap();
+        assert_eq!(result, vec![Hint::Lower]);
+    }
+
+    // Test pour la devinette correcte
+    #[test]
+    fn test_guess_correct() {
+        let mut game = Game::new();
+        game.add_player("Player1".to_string());
+        game.secret_number = 50;
+        game.phase = GamePhase::Playing;
+        let result = game.guess("Player1", 50).unwrap();
+        assert_eq!(result, vec![Hint::Correct]);
+        assert_eq!(game.players.get("Player1").unwrap().score, 1);
+    }
+
+    // Test pour les indices supplémentaires configurés selon la difficulté
+    #[test]
+    fn test_guess_with_extra_hints() {
+        let mut game = Game::new();
+        game.set_hint_providers(Difficulty::Easy, vec![HintKind::Parity.provider(), HintKind::Within(5).provider()]);
+        game.secret_number = 50;
+        game.phase = GamePhase::Playing;
+        assert_eq!(game.guess("Player1", 47).unwrap(), vec![Hint::Higher, Hint::Parity { even: true }, Hint::Within { distance: 5, within: true }]);
+        assert_eq!(game.guess("Player1", 50).unwrap(), vec![Hint::Correct]);
+
+        game.set_difficulty(Difficulty::Hard);
+        game.secret_number = 50;
+        assert_eq!(game.guess("Player1", 47).unwrap(), vec![Hint::Higher]);
+    }
+
+    // Test pour le refus d'une devinette hors de la phase de jeu
+    #[test]
+    fn test_guess_wrong_phase() {
+        let mut game = Game::new();
+        assert_eq!(game.guess("Player1", 50), Err(GuessError::WrongPhase));
+    }
+
+    // Test pour l'ordre de passage en mode tour par tour
+    #[test]
+    fn test_turn_based_order() {
+        let mut game = Game::new();
+        game.set_mode(GameMode::TurnBased, Duration::new(15, 0));
+        game.add_player("Alice".to_string());
+        game.add_player("Bob".to_string());
+        game.secret_number = 50;
+        game.start_game_phase();
+
+        let first = game.current_turn().unwrap().to_string();
+        let second = if first == "Alice" { "Bob" } else { "Alice" };
+        assert_eq!(game.guess(second, 40), Err(GuessError::NotYourTurn));
+        assert_eq!(game.guess(&first, 40), Ok(vec![Hint::Higher]));
+        assert_eq!(game.advance_turn(), Some(second));
+        assert_eq!(game.advance_turn(), Some(first.as_str()));
+    }
+
+    // Test pour le saut d'un joueur inactif en mode tour par tour
+    #[test]
+    fn test_turn_timeout_and_removal() {
+        let mut game = Game::new();
+        game.set_mode(GameMode::TurnBased, Duration::ZERO);
+        game.add_player("Alice".to_string());
+        game.add_player("Bob".to_string());
+        game.start_game_phase();
+        assert!(game.check_turn_timeout());
+
+        let first = game.current_turn().unwrap().to_string();
+        game.remove_player(&first);
+        assert_ne!(game.current_turn(), Some(first.as_str()));
+        assert!(game.current_turn().is_some());
+    }
+
+    // Test pour la lecture des options de configuration du jeu
+    #[test]
+    fn test_parse_mode_and_visibility() {
+        assert_eq!("turn".parse(), Ok(GameMode::TurnBased));
+        assert_eq!("Simultaneous".parse(), Ok(GameMode::Simultaneous));
+        assert_eq!("shared".parse(), Ok(HintVisibility::Shared));
+        assert_eq!("guess".parse(), Ok(HintVisibility::GuessOnly));
+        assert!("everything".parse::<HintVisibility>().is_err());
+        assert_eq!("Spectate".parse(), Ok(LateJoinPolicy::Spectate));
+    }
+
+    // Test pour la profondeur de recherche dichotomique
+    #[test]
+    fn test_binary_search_depth() {
+        assert_eq!(binary_search_depth(50, 100), 1);
+        assert_eq!(binary_search_depth(25, 100), 2);
+        assert_eq!(binary_search_depth(75, 100), 2);
+        assert!((1..=100).all(|number| binary_search_depth(number, 100) <= 7));
+    }
+
+    // Test pour le mode course : chaque joueur a son propre nombre, de même difficulté
+    #[test]
+    fn test_race_secrets() {
+        let mut game = Game::new();
+        game.set_mode(GameMode::Race, Duration::new(15, 0));
+        game.set_difficulty(Difficulty::Medium);
+        game.add_player("Alice".to_string());
+        game.add_player("Bob".to_string());
+        game.start_game_phase();
+        game.add_player("Carol".to_string());
+
+        let secrets = game.race_secrets();
+        assert_eq!(secrets.len(), 3);
+        let depth = binary_search_depth(secrets[0].1, 500);
+        assert!(secrets.iter().all(|(_, secret)| binary_search_depth(*secret, 500) == depth));
+        assert_eq!(depth, race_depth(500));
+        let distinct: BTreeSet<u32> = secrets.iter().map(|(_, secret)| *secret).collect();
+        assert_eq!(distinct.len(), 3); // Chaque joueur a son propre nombre, y compris celui arrivé en cours de manche
+
+        let (name, secret) = secrets[1].clone();
+        game.guess(&name, if secret > 1 { secret - 1 } else { secret + 1 }).unwrap();
+        assert_eq!(game.guess(&name, secret), Ok(vec![Hint::Correct]));
+        assert_eq!(game.attempts(&name), 2);
+        assert_eq!(game.attempts(&secrets[0].0), 0);
+    }
+
+    // Test pour la profondeur des nombres secrets du mode course : le niveau le plus peuplé
+    #[test]
+    fn test_race_depth() {
+        for max in [100, 500, 1000] {
+            let depth = race_depth(max);
+            let count = (1..=max).filter(|&number| binary_search_depth(number, max) == depth).count();
+            assert!(count >= 32, "depth {} has only {} numbers for max {}", depth, count, max);
+        }
+        let mut game = Game::new();
+        game.set_mode(GameMode::Race, Duration::new(15, 0));
+        for index in 0..20 {
+            game.add_player(format!("Player{}", index));
+        }
+        game.start_game_phase();
+        let distinct: BTreeSet<u32> = game.race_secrets().into_iter().map(|(_, secret)| secret).collect();
+        assert_eq!(distinct.len(), 20);
+    }
+
+    // Test pour le vote de difficulté
+    #[test]
+    fn test_vote_difficulty() {
+        let mut game = Game::new();
+        game.vote_difficulty(Difficulty::Medium);
+        assert_eq!(*game.difficulty_votes.get(&Difficulty::Medium).unwrap(), 1);
+    }
+
+    // Test pour déterminer la difficulté après le vote
+    #[test]
+    fn test_determine_difficulty() {
+        let mut game = Game::new();
+        game.vote_difficulty(Difficulty::Medium);
+        game.vote_difficulty(Difficulty::Medium);
+        game.vote_difficulty(Difficulty::Hard);
+        game.determine_difficulty();
+        assert_eq!(game.difficulty, Difficulty::Medium);
+        game.force_difficulty(Difficulty::Easy); // La difficulté imposée par l'hôte l'emporte sur les votes.
+        game.vote_difficulty(Difficulty::Hard);
+        game.determine_difficulty();
+        assert_eq!(game.difficulty, Difficulty::Easy);
+    }
+
+    // Test pour démarrer la phase de vote
+    #[test]
+    fn test_start_voting_phase() {
+        let mut game = Game::new();
+        game.start_voting_phase();
+        assert_eq!(game.phase, GamePhase::Voting);
+        assert!(game.start_time.is_some());
+    }
+
+    // Test pour vérifier la durée de la phase de vote
+    #[test]
+    fn test_check_voting_phase() {
+        let mut game = Game::new();
+        game.start_voting_phase();
+        std::thread::sleep(std::time::Duration::from_secs(21));
+        assert!(game.check_voting_phase());
+    }
+
+    // Test pour démarrer la phase de jeu
+    #[test]
+    fn test_start_game_phase() {
+        let mut game = Game::new();
+        game.start_game_phase();
+        assert_eq!(game.phase, GamePhase::Playing);
+        assert!(game.start_time.is_none());
+    }
+
+    // Test pour le minuteur de la manche
+    #[test]
+    fn test_round_time_remaining() {
+        let mut game = Game::new();
+        game.set_timers(Duration::new(20, 0), Some(Duration::new(60, 0)));
+        assert_eq!(game.round_time_remaining(), None);
+        game.start_game_phase();
+        assert_eq!(game.round_time_remaining(), Some(60));
+        assert!(!game.check_round_timeout());
+    }
+
+    // Test pour une manche sans limite de temps
+    #[test]
+    fn test_round_without_time_limit() {
+        let mut game = Game::new();
+        game.start_game_phase();
+        assert_eq!(game.round_time_remaining(), None);
+        assert!(!game.check_round_timeout());
+    }
+
+    // Test pour la fin de manche faute de temps
+    #[test]
+    fn test_round_timeout() {
+        let mut game = Game::new();
+        game.set_timers(Duration::new(20, 0), Some(Duration::ZERO));
+        game.secret_number = 42;
+        game.add_player("Player1".to_string());
+        game.start_game_phase();
+        assert!(game.check_round_timeout());
+        assert_eq!(game.secret_number(), 42);
+        assert!(game.end_round());
+        assert_eq!(game.round_time_remaining(), None);
+    }
+
+    // Test pour l'enchaînement des manches d'un match
+    #[test]
+    fn test_end_round_keeps_players() {
+        let mut game = Game::new();
+        game.rounds_per_match = 2;
+        game.add_player("Player1".to_string());
+        game.secret_number = 50;
+        game.start_game_phase();
+        game.guess("Player1", 50).unwrap();
+        assert!(!game.end_round());
+        assert_eq!(game.round, 2);
+        assert_eq!(game.phase, GamePhase::Voting);
+        assert_eq!(game.players.get("Player1").unwrap().score, 1);
+        assert!(game.end_round());
+    }
+
+    // Test pour le classement et le vainqueur du match
+    #[test]
+    fn test_standings_and_winner() {
+        let mut game = Game::new();
+        game.add_player("Alice".to_string());
+        game.add_player("Bob".to_string());
+        assert_eq!(game.match_winner(), None);
+        game.players.get_mut("Bob").unwrap().increment_score();
+        assert_eq!(game.standings(), vec![("Bob".to_string(), 1), ("Alice".to_string(), 0)]);
+        assert_eq!(game.match_winner(), Some("Bob".to_string()));
+        game.players.get_mut("Alice").unwrap().increment_score();
+        assert_eq!(game.match_winner(), None);
+    }
+
+    // Test pour terminer le jeu
+    #[test]
+    fn test_end_game() {
+        let mut game = Game::new();
+        game.add_player("Player1".to_string());
+        game.players.get_mut("Player1").unwrap().increment_score();
+        game.round = 3;
+        game.end_game();
+        assert_eq!(game.players.get("Player1").unwrap().score, 0);
+        assert_eq!(game.round, 1);
+        assert_eq!(game.phase, GamePhase::Voting);
+
+        game.remove_player("Player1");
+        game.end_game();
+        assert_eq!(game.phase, GamePhase::Identification);
+    }
+}
+
@@ -0,0 +1,184 @@
+use std::str::FromStr;
+use crate::protocol::{Hint, Temperature};
+
+// Trait implémenté par les fournisseurs d'indices.
+// Un fournisseur reçoit le nombre secret, la devinette et la borne supérieure de la plage, et renvoie un indice (ou rien).
+pub trait HintProvider: Send {
+    fn hint(&self, secret: u32, guess: u32, max: u32) -> Option<Hint>;
+}
+
+// Indice de base "plus/moins", toujours donné au joueur.
+pub struct HigherLower;
+
+impl HigherLower {
+    // Compare la devinette au nombre secret.
+    pub fn compare(secret: u32, guess: u32) -> Hint {
+        if guess < secret { // Si la devinette est inférieure au nombre secret.
+            Hint::Higher
+        } else if guess > secret { // Si la devinette est supérieure au nombre secret.
+            Hint::Lower
+        } else { // Si la devinette est correcte.
+            Hint::Correct
+        }
+    }
+}
+
+impl HintProvider for HigherLower {
+    fn hint(&self, secret: u32, guess: u32, _max: u32) -> Option<Hint> {
+        Some(HigherLower::compare(secret, guess))
+    }
+}
+
+// Indice "chaud/froid" selon la distance à la devinette, relative à la taille de la plage.
+pub struct HotCold;
+
+impl HintProvider for HotCold {
+    fn hint(&self, secret: u32, guess: u32, max: u32) -> Option<Hint> {
+        let percent = secret.abs_diff(guess) as u64 * 100 / max.max(1) as u64;
+        Some(Hint::Temperature(match percent {
+            0..=1 => Temperature::Burning,
+            2..=5 => Temperature::Hot,
+            6..=10 => Temperature::Warm,
+            11..=25 => Temperature::Cold,
+            _ => Temperature::Freezing,
+        }))
+    }
+}
+
+// Indice sur la parité du nombre secret.
+pub struct Parity;
+
+impl HintProvider for Parity {
+    fn hint(&self, secret: u32, _guess: u32, _max: u32) -> Option<Hint> {
+        Some(Hint::Parity { even: secret.is_multiple_of(2) })
+    }
+}
+
+// Indice sur la divisibilité du nombre secret par un diviseur donné.
+pub struct Divisibility(pub u32);
+
+impl HintProvider for Divisibility {
+    fn hint(&self, secret: u32, _guess: u32, _max: u32) -> Option<Hint> {
+        if self.0 == 0 {
+            return None;
+        }
+        Some(Hint::Divisible { divisor: self.0, divisible: secret.is_multiple_of(self.0) })
+    }
+}
+
+// Indice donnant la somme des chiffres du nombre secret.
+pub struct DigitSum;
+
+impl HintProvider for DigitSum {
+    fn hint(&self, secret: u32, _guess: u32, _max: u32) -> Option<Hint> {
+        let mut sum = 0;
+        let mut rest = secret;
+        while rest > 0 {
+            sum += rest % 10;
+            rest /= 10;
+        }
+        Some(Hint::DigitSum(sum))
+    }
+}
+
+// Indice indiquant si la devinette est à moins d'une certaine distance du nombre secret.
+pub struct Within(pub u32);
+
+impl HintProvider for Within {
+    fn hint(&self, secret: u32, guess: u32, _max: u32) -> Option<Hint> {
+        Some(Hint::Within { distance: self.0, within: secret.abs_diff(guess) <= self.0 })
+    }
+}
+
+// Enumération des fournisseurs d'indices sélectionnables dans la configuration.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HintKind {
+    HotCold,
+    Parity,
+    Divisibility(u32),
+    DigitSum,
+    Within(u32),
+}
+
+impl HintKind {
+    // Crée le fournisseur d'indices correspondant.
+    pub fn provider(self) -> Box<dyn HintProvider> {
+        match self {
+            HintKind::HotCold => Box::new(HotCold),
+            HintKind::Parity => Box::new(Parity),
+            HintKind::Divisibility(divisor) => Box::new(Divisibility(divisor)),
+            HintKind::DigitSum => Box::new(DigitSum),
+            HintKind::Within(distance) => Box::new(Within(distance)),
+        }
+    }
+}
+
+impl FromStr for HintKind {
+    type Err = String;
+
+    // Permet de lire un fournisseur d'indices depuis la configuration ("hotcold", "parity", "divisible:3", "digitsum", "within:10").
+    fn from_str(value: &str) -> Result<HintKind, String> {
+        let value = value.trim().to_lowercase();
+        let (name, argument) = match value.split_once(':') {
+            Some((name, argument)) => (name, Some(argument.parse::<u32>().map_err(|_| format!("invalid hint argument: {}", value))?)),
+            None => (value.as_str(), None),
+        };
+        match (name, argument) {
+            ("hotcold", None) => Ok(HintKind::HotCold),
+            ("parity", None) => Ok(HintKind::Parity),
+            ("divisible", Some(divisor)) if divisor > 0 => Ok(HintKind::Divisibility(divisor)),
+            ("digitsum", None) => Ok(HintKind::DigitSum),
+            ("within", Some(distance)) => Ok(HintKind::Within(distance)),
+            _ => Err(format!("unknown hint: {}", value)),
+        }
+    }
+}
+
+// Lit une liste de fournisseurs d'indices séparés par des virgules (par exemple "hotcold,divisible:3").
+pub fn parse_hint_kinds(value: &str) -> Result<Vec<HintKind>, String> {
+    value.split(',').filter(|kind| !kind.trim().is_empty()).map(str::parse).collect()
+}
+
+// Tests unitaires pour le module hint
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour l'indice plus/moins
+    #[test]
+    fn test_higher_lower() {
+        assert_eq!(HigherLower.hint(50, 40, 100), Some(Hint::Higher));
+        assert_eq!(HigherLower.hint(50, 60, 100), Some(Hint::Lower));
+        assert_eq!(HigherLower.hint(50, 50, 100), Some(Hint::Correct));
+    }
+
+    // Test pour les tranches de distance chaud/froid
+    #[test]
+    fn test_hot_cold() {
+        assert_eq!(HotCold.hint(500, 505, 1000), Some(Hint::Temperature(Temperature::Burning)));
+        assert_eq!(HotCold.hint(500, 540, 1000), Some(Hint::Temperature(Temperature::Hot)));
+        assert_eq!(HotCold.hint(500, 400, 1000), Some(Hint::Temperature(Temperature::Warm)));
+        assert_eq!(HotCold.hint(500, 300, 1000), Some(Hint::Temperature(Temperature::Cold)));
+        assert_eq!(HotCold.hint(500, 1, 1000), Some(Hint::Temperature(Temperature::Freezing)));
+    }
+
+    // Test pour les indices sur les propriétés du nombre secret
+    #[test]
+    fn test_number_properties() {
+        assert_eq!(Parity.hint(42, 1, 100), Some(Hint::Parity { even: true }));
+        assert_eq!(Divisibility(7).hint(42, 1, 100), Some(Hint::Divisible { divisor: 7, divisible: true }));
+        assert_eq!(Divisibility(5).hint(42, 1, 100), Some(Hint::Divisible { divisor: 5, divisible: false }));
+        assert_eq!(DigitSum.hint(742, 1, 1000), Some(Hint::DigitSum(13)));
+        assert_eq!(Within(10).hint(42, 35, 100), Some(Hint::Within { distance: 10, within: true }));
+        assert_eq!(Within(10).hint(42, 20, 100), Some(Hint::Within { distance: 10, within: false }));
+    }
+
+    // Test pour la lecture de la configuration des indices
+    #[test]
+    fn test_parse_hint_kinds() {
+        assert_eq!(parse_hint_kinds("hotcold, divisible:3,within:10"), Ok(vec![HintKind::HotCold, HintKind::Divisibility(3), HintKind::Within(10)]));
+        assert_eq!(parse_hint_kinds(""), Ok(vec![]));
+        assert!(parse_hint_kinds("divisible:0").is_err());
+        assert!(parse_hint_kinds("colour").is_err());
+    }
+}
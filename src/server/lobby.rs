@@ -0,0 +1,180 @@
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
+
+// Enumération représentant les raisons pour lesquelles une action du salon d'attente peut être refusée.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LobbyError {
+    NotInLobby, // La partie n'est pas dans le salon d'attente.
+    NotHost, // Seul l'hôte peut lancer le match.
+    NotEnoughPlayers, // Il n'y a pas assez de joueurs pour lancer le match.
+    Spectating, // Les spectateurs ne participent pas au salon.
+}
+
+// `Lobby` est le salon d'attente avant la phase de vote : les joueurs se déclarent prêts,
+// l'hôte peut lancer le match, et un compte à rebours le lance quand tout le monde est prêt.
+pub struct Lobby {
+    pub enabled: bool, // Sans salon, la phase de vote démarre dès l'arrivée du premier joueur.
+    pub min_players: usize, // Nombre minimal de joueurs pour lancer le match.
+    pub designated_host: Option<String>, // Joueur désigné comme hôte : il le devient dès son arrivée.
+    countdown: Duration, // Délai avant le lancement automatique une fois tous les joueurs prêts.
+    arrivals: Vec<String>, // Joueurs par ordre d'arrivée : le premier est l'hôte.
+    ready: BTreeSet<String>, // Joueurs qui se sont déclarés prêts.
+    countdown_start: Option<Instant>, // Instant où tous les joueurs sont devenus prêts.
+}
+
+impl Lobby {
+    pub fn new(enabled: bool, min_players: usize, countdown: Duration) -> Lobby {
+        Lobby { enabled, min_players, designated_host: None, countdown, arrivals: vec![], ready: BTreeSet::new(), countdown_start: None }
+    }
+
+    // Enregistre l'arrivée d'un joueur. Il n'est pas prêt : le compte à rebours éventuel est annulé.
+    // L'hôte désigné passe devant les autres joueurs.
+    pub fn join(&mut self, name: &str) {
+        if !self.contains(name) {
+            if self.designated_host.as_deref() == Some(name) {
+                self.arrivals.insert(0, name.to_string());
+            } else {
+                self.arrivals.push(name.to_string());
+            }
+        }
+        self.update();
+    }
+
+    // Indique si le joueur est présent.
+    pub fn contains(&self, name: &str) -> bool {
+        self.arrivals.iter().any(|player| player == name)
+    }
+
+    // Donne le rôle d'hôte à un joueur présent. Renvoie `false` si le joueur n'est pas présent.
+    pub fn transfer_host(&mut self, name: &str) -> bool {
+        match self.arrivals.iter().position(|player| player == name) {
+            Some(position) => {
+                let player = self.arrivals.remove(position);
+                self.arrivals.insert(0, player);
+                true
+            },
+            None => false,
+        }
+    }
+
+    // Enregistre le départ d'un joueur. Si c'était l'hôte, le joueur arrivé juste après lui le remplace.
+    pub fn leave(&mut self, name: &str) {
+        self.arrivals.retain(|player| player != name);
+        self.ready.remove(name);
+        self.update();
+    }
+
+    // Renvoie le nom de l'hôte (le joueur présent depuis le plus longtemps).
+    pub fn host(&self) -> Option<&str> {
+        self.arrivals.first().map(String::as_str)
+    }
+
+    // Renvoie les noms des joueurs prêts, triés par ordre alphabétique.
+    pub fn ready_names(&self) -> Vec<String> {
+        self.ready.iter().cloned().collect()
+    }
+
+    // Change l'état "prêt" d'un joueur et renvoie son nouvel état.
+    pub fn toggle_ready(&mut self, name: &str) -> bool {
+        let ready = if self.ready.remove(name) {
+            false
+        } else {
+            self.ready.insert(name.to_string());
+            true
+        };
+        self.update();
+        ready
+    }
+
+    // Vérifie que le joueur peut lancer le match : il doit être l'hôte, avec assez de joueurs présents.
+    pub fn can_start(&self, name: &str) -> Result<(), LobbyError> {
+        if self.host() != Some(name) {
+            return Err(LobbyError::NotHost);
+        }
+        if self.arrivals.len() < self.min_players {
+            return Err(LobbyError::NotEnoughPlayers);
+        }
+        Ok(())
+    }
+
+    // Démarre le compte à rebours quand assez de joueurs sont présents et tous prêts, et l'annule sinon.
+    fn update(&mut self) {
+        let all_ready = self.arrivals.len() >= self.min_players.max(1) && self.arrivals.iter().all(|player| self.ready.contains(player));
+        if !all_ready {
+            self.countdown_start = None;
+        } else if self.countdown_start.is_none() {
+            self.countdown_start = Some(Instant::now());
+        }
+    }
+
+    // Renvoie le temps restant (en secondes, arrondi au supérieur) avant le lancement automatique du match.
+    pub fn countdown_remaining(&self) -> Option<u32> {
+        let start = self.countdown_start?;
+        let remaining = self.countdown.saturating_sub(start.elapsed());
+        Some(remaining.as_secs_f64().ceil() as u32)
+    }
+
+    // Remet le salon à zéro (au début de la phase de vote, ou au retour dans le salon) : plus personne n'est prêt.
+    pub fn reset(&mut self) {
+        self.ready.clear();
+        self.countdown_start = None;
+    }
+}
+
+// Tests unitaires pour le module lobby
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour l'hôte et sa succession
+    #[test]
+    fn test_host() {
+        let mut lobby = Lobby::new(true, 2, Duration::from_secs(10));
+        assert_eq!(lobby.host(), None);
+        lobby.join("Alice");
+        lobby.join("Bob");
+        assert_eq!(lobby.host(), Some("Alice"));
+        assert_eq!(lobby.can_start("Bob"), Err(LobbyError::NotHost));
+        assert_eq!(lobby.can_start("Alice"), Ok(()));
+        lobby.leave("Alice");
+        assert_eq!(lobby.host(), Some("Bob"));
+        assert_eq!(lobby.can_start("Bob"), Err(LobbyError::NotEnoughPlayers));
+        lobby.join("Carol");
+        assert!(lobby.transfer_host("Carol"));
+        assert_eq!(lobby.host(), Some("Carol"));
+        assert!(!lobby.transfer_host("Alice"));
+    }
+
+    // Test pour l'hôte désigné par la configuration
+    #[test]
+    fn test_designated_host() {
+        let mut lobby = Lobby::new(true, 2, Duration::from_secs(10));
+        lobby.designated_host = Some("Bob".to_string());
+        lobby.join("Alice");
+        assert_eq!(lobby.host(), Some("Alice"));
+        lobby.join("Bob");
+        assert_eq!(lobby.host(), Some("Bob"));
+        lobby.leave("Bob");
+        assert_eq!(lobby.host(), Some("Alice"));
+    }
+
+    // Test pour le compte à rebours une fois tous les joueurs prêts
+    #[test]
+    fn test_ready_countdown() {
+        let mut lobby = Lobby::new(true, 2, Duration::from_secs(5));
+        lobby.join("Alice");
+        assert!(lobby.toggle_ready("Alice"));
+        assert_eq!(lobby.countdown_remaining(), None); // Pas assez de joueurs.
+        lobby.join("Bob");
+        assert!(lobby.toggle_ready("Bob"));
+        assert_eq!(lobby.countdown_remaining(), Some(5));
+        lobby.join("Carol"); // Un nouveau joueur n'est pas prêt : le compte à rebours est annulé.
+        assert_eq!(lobby.countdown_remaining(), None);
+        lobby.leave("Carol");
+        assert!(!lobby.toggle_ready("Bob"));
+        assert_eq!(lobby.countdown_remaining(), None);
+        assert_eq!(lobby.ready_names(), vec!["Alice".to_string()]);
+        lobby.reset();
+        assert!(lobby.ready_names().is_empty());
+    }
+}
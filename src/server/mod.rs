@@ -0,0 +1,646 @@
+pub mod accounts;
+pub mod admin;
+pub mod chat;
+pub mod console;
+pub mod config;
+pub mod game;
+pub mod hint;
+pub mod lobby;
+pub mod player;
+pub mod shutdown;
+pub mod util;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::net::{Shutdown, SocketAddr, TcpListener};
+use std::io::{self, BufReader, ErrorKind, Write};
+use crate::codec::{decode, read_frame};
+use crate::i18n::{tr, Language, MessageId};
+use crate::tls;
+use crate::transport::Stream;
+use crate::protocol::{AuthError, ChatError, ClientMessage, GamePhase, Hint, ServerMessage};
+use accounts::{failure_delay, name_key, Accounts, MAX_AUTH_FAILURES};
+use chat::{BlockedWords, Chat, ChatFilter, NoFilter, RateLimiter};
+use config::ServerConfig;
+use game::{Game, GameMode, HintVisibility};
+use lobby::{Lobby, LobbyError};
+use shutdown::{save_leaderboard, ShutdownSignal};
+use util::{broadcast_except, broadcast_message, send_message, send_to, send_to_spectators, Connection};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use crossbeam::channel::unbounded;
+use crossbeam::thread::scope;
+
+pub fn run(config: ServerConfig) {
+    // Création du serveur TCP écoutant sur le port 7878
+    let listener = TcpListener::bind("0.0.0.0:7878").expect("Could not bind");
+    let encryption = if config.tls_cert.is_some() { " (TLS)" } else { "" };
+    println!("Server listening on port 7878{}", encryption);
+    let signal = Arc::new(ShutdownSignal::new());
+    let handler_signal = Arc::clone(&signal);
+    ctrlc::set_handler(move || {
+        // Premier signal (SIGINT ou SIGTERM) : arrêt en douceur. Un second signal arrête le serveur immédiatement.
+        if !handler_signal.request(None) {
+            std::process::exit(1);
+        }
+        println!("Shutdown requested");
+    }).expect("Could not install the signal handler");
+    serve_until(listener, config, &signal);
+    println!("Server stopped");
+}
+
+// Fait tourner le serveur sur un port déjà ouvert (par exemple un port choisi par le système, pour les tests).
+pub fn serve(listener: TcpListener, config: ServerConfig) {
+    serve_until(listener, config, &ShutdownSignal::new());
+}
+
+// Fait tourner le serveur jusqu'à ce que son arrêt soit demandé : il n'accepte alors plus de connexions, annonce l'arrêt
+// aux joueurs, laisse la manche en cours se terminer (dans la limite du délai de grâce), enregistre le classement
+// et ferme les connexions. La fonction se termine quand tous les threads du serveur sont terminés.
+pub fn serve_until(listener: TcpListener, config: ServerConfig, signal: &ShutdownSignal) {
+    // Initialisation du jeu partagé entre threads avec Arc et Mutex
+    let mut game = Game::new();
+    game.set_timers(config.voting_duration, config.round_duration); // Applique les durées configurées
+    game.rounds_per_match = config.rounds_per_match;
+    game.set_mode(config.mode, config.turn_duration);
+    game.hint_visibility = config.hint_visibility;
+    game.spectators_see_secret = config.spectators_see_secret;
+    game.late_join = config.late_join;
+    game.lobby = Lobby::new(config.lobby, config.min_players, config.lobby_countdown);
+    game.lobby.designated_host = config.host.clone();
+    for (difficulty, kinds) in &config.hints {
+        game.set_hint_providers(*difficulty, kinds.iter().map(|kind| kind.provider()).collect()); // Indices supplémentaires par difficulté
+    }
+    let filter: Box<dyn ChatFilter> = if config.chat_blocked_words.is_empty() {
+        Box::new(NoFilter)
+    } else {
+        Box::new(BlockedWords::new(config.chat_blocked_words.clone(), config.chat_reject_blocked))
+    };
+    game.chat = Chat::new(config.chat_max_length, RateLimiter::new(config.chat_rate_limit, config.chat_rate_window), filter);
+    let game = Arc::new(Mutex::new(game));
+    let accounts = Arc::new(match &config.accounts_file {
+        Some(path) => Accounts::load(path.clone(), config.allow_guests).expect("Could not read the accounts file"),
+        None => Accounts::new(config.allow_guests),
+    });
+    let tls = match (&config.tls_cert, &config.tls_key) {
+        (Some(cert), Some(key)) => Some(tls::server_config(cert, key).expect("Could not load the TLS certificate")),
+        (None, None) => None,
+        _ => panic!("TLS_CERT and TLS_KEY must be set together"),
+    };
+    
+    // Liste des clients connectés partagée entre threads
+    let clients = Arc::new(Mutex::new(Vec::new()));
+
+    // Utilisation de "scope" pour créer une boucle d'écoute des clients tout en gérant le multithreading
+    scope(|s| {
+        // Thread gérant la phase de vote, le lancement du jeu et le minuteur des manches
+        s.spawn(|_| {
+            let game = Arc::clone(&game); // Clonage des références pour utilisation dans ce thread
+            let clients = Arc::clone(&clients);
+            let mut last_announced = None; // Dernier temps restant annoncé aux joueurs (évite les doublons)
+            let mut shutdown_deadline = None; // Instant où le serveur s'arrête, une fois l'arrêt demandé
+
+            loop {
+                {
+                    let mut game = game.lock().unwrap();
+                    if signal.is_requested() {
+                        let deadline = *shutdown_deadline.get_or_insert_with(|| announce_shutdown(&game, &clients.lock().unwrap(), signal, config.shutdown_grace));
+                        // Seule une manche en cours retarde l'arrêt : aucune nouvelle phase ne commence.
+                        if game.phase != GamePhase::Playing || Instant::now() >= deadline {
+                            close_game(&mut game, &clients.lock().unwrap(), &config);
+                            signal.finish();
+                            break;
+                        }
+                    }
+                    match game.phase {
+                        GamePhase::Voting => {
+                            // Si le compte à rebours est terminé, démarre le jeu avec la difficulté choisie
+                            if game.check_voting_phase() {
+                                start_round(&mut game, &clients.lock().unwrap());
+                                last_announced = None;
+                            } else {
+                                let remaining = game.voting_time_remaining();
+                                println!("Countdown: {}", remaining); // Affiche le temps restant
+                                broadcast_message(ServerMessage::VotingCountdown(remaining), &clients.lock().unwrap()); // Et l'envoie aux joueurs
+                            }
+                        },
+                        GamePhase::Playing => {
+                            if game.check_round_timeout() {
+                                // Personne n'a trouvé le nombre à temps : on le révèle et on termine la manche
+                                println!("Round timed out");
+                                stop_round(&mut game, &clients.lock().unwrap());
+                            } else {
+                                if game.check_turn_timeout() {
+                                    // Le joueur courant n'a pas deviné à temps : il passe son tour
+                                    let skipped = game.current_turn().unwrap_or_default().to_string();
+                                    let clients = clients.lock().unwrap();
+                                    broadcast_message(ServerMessage::TurnSkipped(skipped), &clients);
+                                    if let Some(player) = game.advance_turn() {
+                                        broadcast_message(ServerMessage::Turn(player.to_string()), &clients);
+                                    }
+                                }
+                                if let Some(remaining) = game.round_time_remaining() {
+                                    // Annonce le temps restant toutes les 10 secondes, puis chaque seconde à la fin
+                                    if should_announce(remaining) && last_announced != Some(remaining) {
+                                        broadcast_message(ServerMessage::TimeRemaining(remaining), &clients.lock().unwrap());
+                                        last_announced = Some(remaining);
+                                    }
+                                }
+                            }
+                        },
+                        GamePhase::Identification => {
+                            if game.check_lobby_countdown() {
+                                game.start_voting_phase(); // Tous les joueurs sont prêts : la phase de vote commence
+                            }
+                        },
+                    }
+                }
+                // Attendre 1 seconde avant de vérifier à nouveau les minuteurs
+                thread::sleep(Duration::from_secs(1));
+            }
+        });
+
+        if config.console {
+            // Console d'administration sur l'entrée standard du serveur. Un thread lit l'entrée standard
+            // et transmet les lignes lues à la console.
+            let (sender, lines) = unbounded();
+            #[cfg(unix)]
+            s.spawn(move |_| console::read_stdin(sender, signal));
+            #[cfg(not(unix))]
+            thread::spawn(move || console::read_stdin(sender)); // Lecture bloquante : le thread est abandonné à l'arrêt
+            s.spawn(|_| console::run(lines, &game, &clients, signal));
+        }
+
+        // Connexions ouvertes (y compris celles des clients qui n'ont pas encore rejoint la partie), fermées à l'arrêt du serveur.
+        // Ce sont des clones des `Stream` des clients, pour qu'une connexion TLS soit fermée proprement (avec `close_notify`).
+        let connections: Arc<Mutex<HashMap<SocketAddr, Stream>>> = Arc::new(Mutex::new(HashMap::new()));
+
+        // Boucle pour accepter les nouvelles connexions des clients, jusqu'à ce que l'arrêt du serveur soit demandé
+        listener.set_nonblocking(true).expect("Could not set the listener to non-blocking mode");
+        while !signal.is_requested() {
+            let (stream, address) = match listener.accept() {
+                Ok(connection) => connection,
+                Err(error) if error.kind() == ErrorKind::WouldBlock => {
+                    thread::sleep(Duration::from_millis(50)); // Aucune connexion en attente
+                    continue;
+                },
+                Err(error) => {
+                    eprintln!("Failed to accept connection: {}", error);
+                    continue;
+                },
+            };
+            stream.set_nonblocking(false).ok(); // La connexion est lue de façon bloquante par son propre thread
+            let stream = match &tls {
+                Some(config) => match Stream::server(stream, Arc::clone(config)) {
+                    Ok(stream) => stream,
+                    Err(error) => {
+                        eprintln!("Failed to set up TLS: {}", error);
+                        continue;
+                    },
+                },
+                None => Stream::plain(stream),
+            };
+            if let Ok(clone) = stream.try_clone() {
+                connections.lock().unwrap().insert(address, clone);
+            }
+            let game = Arc::clone(&game);
+            let clients = Arc::clone(&clients);
+            let accounts = Arc::clone(&accounts);
+            let connections = Arc::clone(&connections);
+
+            // Pour chaque client connecté, un nouveau thread est créé pour gérer la communication
+            s.spawn(move |_| {
+                println!("New client connected");
+                handle_client(stream, game, clients, accounts); // Gère la communication avec ce client spécifique
+                connections.lock().unwrap().remove(&address);
+            });
+        }
+
+        // Attend la fin de la partie, puis ferme toutes les connexions : les threads des clients se terminent
+        while !signal.is_finished() {
+            thread::sleep(Duration::from_millis(50));
+        }
+        for stream in connections.lock().unwrap().values() {
+            stream.shutdown(Shutdown::Both).ok();
+        }
+    }).expect("Thread pool failed"); // Gestion d'erreurs si le pool de threads échoue
+}
+
+// Annonce l'arrêt du serveur aux clients et renvoie l'instant où il aura lieu.
+// Une manche en cours dispose du délai de grâce pour se terminer ; sinon, l'arrêt est immédiat.
+fn announce_shutdown(game: &Game, clients: &[Connection], signal: &ShutdownSignal, grace: Duration) -> Instant {
+    let grace = if game.phase == GamePhase::Playing { grace } else { Duration::ZERO };
+    println!("Server shutting down in {} seconds", grace.as_secs());
+    broadcast_message(ServerMessage::ServerShutdown { reason: signal.reason(), seconds: grace.as_secs() as u32 }, clients);
+    Instant::now() + grace
+}
+
+// Termine la partie avant l'arrêt du serveur : arrête la manche qui n'a pas pu se terminer à temps et enregistre le classement.
+fn close_game(game: &mut Game, clients: &[Connection], config: &ServerConfig) {
+    let standings = game.standings(); // Avant la fin éventuelle du match, qui remet les scores à zéro
+    if game.phase == GamePhase::Playing {
+        println!("Stopping the round in progress");
+        stop_round(game, clients);
+    }
+    println!("Final standings: {:?}", standings);
+    if let Some(path) = &config.leaderboard_file {
+        if let Err(error) = save_leaderboard(path, &standings) {
+            eprintln!("Could not save the leaderboard to {}: {}", path.display(), error);
+        }
+    }
+    io::stdout().flush().ok();
+}
+
+// Démarre la manche à la fin de la phase de vote, avec la difficulté choisie.
+fn start_round(game: &mut Game, clients: &[Connection]) {
+    game.determine_difficulty(); // Détermine la difficulté basée sur les votes
+    broadcast_message(ServerMessage::GameStart(game.difficulty), clients);
+    game.start_game_phase(); // Passe à la phase de jeu
+    if let Some(duration) = game.round_duration_secs() {
+        broadcast_message(ServerMessage::TimerStart(duration), clients); // Annonce la durée de la manche
+    }
+    if let Some(player) = game.current_turn() {
+        broadcast_message(ServerMessage::Turn(player.to_string()), clients); // Annonce le premier joueur en mode tour par tour
+    }
+}
+
+// Termine la manche en cours sans vainqueur (temps écoulé, ou sur décision de l'hôte) : le nombre secret est révélé.
+fn stop_round(game: &mut Game, clients: &[Connection]) {
+    if game.mode == GameMode::Race {
+        broadcast_message(ServerMessage::RaceTimeout { secrets: game.race_secrets() }, clients);
+    } else {
+        let secret = game.secret_number();
+        println!("The secret number was {}", secret);
+        broadcast_message(ServerMessage::RoundTimeout { secret }, clients);
+    }
+    broadcast_message(ServerMessage::GameEnd, clients);
+    finish_round(game, clients);
+}
+
+// Termine la manche en cours : diffuse le classement, puis le résultat final si c'était la dernière manche du match.
+fn finish_round(game: &mut Game, clients: &[Connection]) {
+    let round = game.round;
+    let admitted = game.has_waiting(); // Les joueurs arrivés pendant la manche entrent dans la partie
+    let match_over = game.end_round();
+    if admitted {
+        broadcast_message(player_list(game), clients);
+    }
+    broadcast_message(ServerMessage::Standings { round, rounds: game.rounds_per_match, standings: game.standings() }, clients);
+    if match_over {
+        let winner = game.match_winner();
+        println!("Match over, winner: {:?}", winner);
+        broadcast_message(ServerMessage::MatchEnd { standings: game.standings(), winner }, clients);
+        game.end_game(); // Remet les scores à zéro, les joueurs restent connectés pour le match suivant
+        if game.in_lobby() {
+            broadcast_message(lobby_state(game), clients); // Les joueurs retournent dans le salon
+        }
+    }
+}
+
+// Construit la liste des joueurs, des spectateurs et des joueurs en attente envoyée aux clients.
+fn player_list(game: &Game) -> ServerMessage {
+    ServerMessage::PlayerList { players: game.player_names(), spectators: game.spectator_names(), waiting: game.waiting_names() }
+}
+
+// Construit l'état du salon d'attente envoyé aux clients.
+fn lobby_state(game: &Game) -> ServerMessage {
+    ServerMessage::Lobby {
+        host: game.lobby.host().map(str::to_string),
+        ready: game.lobby.ready_names(),
+        min_players: game.lobby.min_players as u32,
+        countdown: game.lobby.countdown_remaining(),
+    }
+}
+
+// Renvoie le message expliquant le refus d'une action du salon d'attente.
+fn lobby_error_text(language: Language, error: LobbyError) -> String {
+    let id = match error {
+        LobbyError::NotInLobby => MessageId::NotInLobby,
+        LobbyError::NotHost => MessageId::NotHost,
+        LobbyError::NotEnoughPlayers => MessageId::NotEnoughPlayers,
+        LobbyError::Spectating => MessageId::LobbySpectating,
+    };
+    tr(language, id, &[])
+}
+
+// Construit l'état de la partie envoyé à un client qui arrive en cours de vote ou de manche.
+fn game_state(game: &Game) -> ServerMessage {
+    ServerMessage::GameState {
+        phase: game.phase,
+        difficulty: Some(game.difficulty).filter(|_| game.phase == GamePhase::Playing), // La difficulté n'est connue qu'une fois le vote terminé
+        round: game.round,
+        rounds: game.rounds_per_match,
+        remaining: game.phase_time_remaining(),
+    }
+}
+
+// Renvoie l'heure actuelle en secondes depuis le 1er janvier 1970 (UTC), pour horodater les messages du chat.
+fn timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
+
+// Indique si le temps restant d'une manche doit être annoncé aux joueurs.
+fn should_announce(remaining: u32) -> bool {
+    remaining.is_multiple_of(10) || remaining <= 5
+}
+
+pub fn handle_client(mut stream: Stream, game: Arc<Mutex<Game>>, clients: Arc<Mutex<Vec<Connection>>>, accounts: Arc<Accounts>) {
+    if let Err(error) = stream.handshake() {
+        // Certificat refusé par le client, ou client qui ne parle pas TLS
+        println!("TLS handshake failed: {}", error);
+        return;
+    }
+    let mut reader = BufReader::new(stream.try_clone().unwrap()); // Permet de lire les données du client
+    let address = stream.peer_addr().ok(); // Adresse du client, pour le retirer de la liste à sa déconnexion
+    let mut authenticated: Option<String> = None; // Compte auquel le client s'est connecté avant de rejoindre la partie
+    let mut failures = 0; // Tentatives de connexion ou de création de compte refusées
+
+    // Attend le message `Join` (ou `JoinAsSpectator`) du client, qui donne le nom du joueur et sa langue.
+    // Le client peut d'abord se connecter à un compte (ou en créer un) pour utiliser un nom réservé.
+    // Chaque échec retarde la réponse un peu plus, et la connexion est fermée après trop d'échecs.
+    let (player_name, language, spectator) = loop {
+        let result = match read_frame(&mut reader) {
+            Ok(None) | Err(_) => {
+                println!("Client disconnected before joining");
+                return;
+            },
+            Ok(Some(payload)) => {
+                match decode(&payload) {
+                    Ok(ClientMessage::Join { name, language }) => break (name.trim().to_string(), language, false), // Nettoie le nom du joueur
+                    Ok(ClientMessage::JoinAsSpectator { name, language }) => break (name.trim().to_string(), language, true),
+                    Ok(ClientMessage::Login { name, password }) => {
+                        let name = name.trim().to_string();
+                        accounts.verify(&name, &password).map(|()| {
+                            println!("Client logged in as {}", name);
+                            name
+                        })
+                    },
+                    Ok(ClientMessage::Register { name, password }) => {
+                        let name = name.trim().to_string();
+                        accounts.register(&name, &password).map(|()| {
+                            println!("Account {} registered", name);
+                            name
+                        })
+                    },
+                    Ok(message) => {
+                        eprintln!("Expected a Join message, got {:?}", message);
+                        continue;
+                    },
+                    Err(_) => {
+                        eprintln!("Failed to deserialize");
+                        continue;
+                    },
+                }
+            },
+        };
+        match result {
+            Ok(name) => {
+                authenticated = Some(name.clone());
+                send_message(ServerMessage::Authenticated(name), &mut stream);
+            },
+            Err(error) => {
+                failures += 1;
+                thread::sleep(failure_delay(failures));
+                if failures >= MAX_AUTH_FAILURES {
+                    println!("Closing connection after {} failed authentication attempts", failures);
+                    send_message(ServerMessage::AuthRejected(AuthError::TooManyAttempts), &mut stream);
+                    return;
+                }
+                send_message(ServerMessage::AuthRejected(error), &mut stream);
+            },
+        }
+    };
+
+    if let Err(error) = accounts.check_join(&player_name, authenticated.as_deref()) {
+        // Le nom appartient à un compte auquel le client ne s'est pas connecté, ou le serveur refuse les invités
+        println!("Client {} was refused: {:?}", player_name, error);
+        send_message(ServerMessage::AuthRejected(error), &mut stream);
+        return;
+    }
+
+    if game.lock().unwrap().bans.is_banned(&player_name, address.map(|address| address.ip())) {
+        // Le joueur (ou son adresse) a été exclu de la partie par l'hôte
+        println!("Banned client {} was refused", player_name);
+        send_message(ServerMessage::Notice(tr(language, MessageId::Banned, &[])), &mut stream);
+        return;
+    }
+
+    {
+        let mut clients = clients.lock().unwrap();
+        if clients.iter().any(|client| name_key(&client.name) == name_key(&player_name)) {
+            // Deux connexions ne peuvent pas partager un nom, même écrit avec d'autres majuscules :
+            // le joueur, ses messages et ses exclusions seraient confondus
+            println!("Client {} was refused: name already in use", player_name);
+            send_message(ServerMessage::AuthRejected(AuthError::NameInUse), &mut stream);
+            return;
+        }
+        // Ajoute le nouveau client à la liste des clients
+        clients.push(Connection { name: player_name.clone(), address, stream: stream.try_clone().unwrap() });
+    }
+    send_message(ServerMessage::Notice(tr(language, MessageId::Welcome, &[&player_name])), &mut stream);
+
+    {
+        let mut game = game.lock().unwrap();
+        if game.phase != GamePhase::Identification {
+            send_message(game_state(&game), &mut stream); // Informe le nouveau venu de l'état de la partie en cours
+        }
+        if spectator {
+            game.add_spectator(player_name.clone()); // Un spectateur suit la partie sans y participer
+        } else {
+            game.add_player(player_name.clone()); // Ajoute le joueur à la partie
+            if game.phase == GamePhase::Identification && !game.lobby.enabled {
+                game.start_voting_phase(); // Sans salon, démarre la phase de vote dès l'arrivée d'un joueur
+            }
+        }
+        if game.is_waiting(&player_name) {
+            // Arrivé pendant une manche : le joueur attend la suivante, selon la politique configurée
+            let id = if game.is_spectator(&player_name) { MessageId::LateJoinSpectating } else { MessageId::LateJoinQueued };
+            send_message(ServerMessage::Notice(tr(language, id, &[])), &mut stream);
+        }
+        broadcast_message(player_list(&game), &clients.lock().unwrap()); // Informe les joueurs de l'arrivée du nouveau joueur
+        if game.in_lobby() {
+            broadcast_message(lobby_state(&game), &clients.lock().unwrap()); // Le nouveau joueur n'est pas encore prêt
+        }
+    }
+
+    if spectator {
+        println!("Spectator {} is watching the game", player_name);
+    } else {
+        println!("Player {} has joined the game", player_name);
+    }
+
+    loop {
+        match read_frame(&mut reader) {
+            Ok(None) => {
+                // Si la connexion est fermée par le client
+                println!("Player {} disconnected", player_name);
+                break;
+            },
+            Ok(Some(payload)) => {
+                let message: ClientMessage = match decode(&payload) {
+                    Ok(msg) => msg, // Désérialise le message du client
+                    Err(_) => {
+                        eprintln!("Failed to deserialize");
+                        continue;
+                    }
+                };
+                if !matches!(message, ClientMessage::Login { .. } | ClientMessage::Register { .. }) {
+                    println!("Received message from {}: {:?}", player_name, message); // Les mots de passe ne sont jamais affichés
+                }
+
+                match message {
+                    // Gestion des messages de type Guess
+                    ClientMessage::Guess(guess) => {
+                        let mut game = game.lock().unwrap();
+                        let hints = match game.guess(&player_name, guess) {
+                            Ok(hints) => hints,
+                            Err(error) => {
+                                // Si le jeu n'est pas en cours ou que ce n'est pas son tour, l'utilisateur ne peut pas deviner
+                                send_message(ServerMessage::GuessRejected(error), &mut stream);
+                                continue;
+                            }
+                        };
+                        // Envoie un indice au client concernant sa devinette
+                        println!("Sending hints to {}: {:?}", player_name, hints);
+                        send_message(ServerMessage::Hint(hints.clone()), &mut stream);
+
+                        // Diffuse la devinette aux autres clients, selon la visibilité des indices configurée
+                        let clients = clients.lock().unwrap();
+                        match game.hint_visibility {
+                            // En mode course, seul le nombre de tentatives est montré aux autres joueurs
+                            _ if game.mode == GameMode::Race => broadcast_except(ServerMessage::RaceProgress(player_name.clone(), game.attempts(&player_name)), &clients, address),
+                            HintVisibility::Private => {},
+                            HintVisibility::GuessOnly => broadcast_except(ServerMessage::Guess(guess, player_name.clone()), &clients, address),
+                            HintVisibility::Shared => broadcast_except(ServerMessage::GuessWithHint(guess, player_name.clone(), hints.clone()), &clients, address),
+                        }
+
+                        if hints.contains(&Hint::Correct) {
+                            // Si le joueur a gagné, informe tous les clients et termine la manche
+                            broadcast_message(ServerMessage::PlayerWon(player_name.clone()), &clients);
+                            if game.spectators_see_secret {
+                                send_to_spectators(ServerMessage::SecretRevealed(guess), &clients, &game.spectator_names()); // La devinette gagnante est le nombre secret
+                            }
+                            broadcast_message(ServerMessage::GameEnd, &clients);
+                            finish_round(&mut game, &clients);
+                        } else if let Some(player) = game.advance_turn() {
+                            // En mode tour par tour, la main passe au joueur suivant
+                            broadcast_message(ServerMessage::Turn(player.to_string()), &clients);
+                        }
+                    },
+                    // Gestion des votes de difficulté
+                    ClientMessage::DifficultyVote(difficulty) => {
+                        let mut game = game.lock().unwrap();
+                        if game.is_spectator(&player_name) {
+                            // Les spectateurs ne votent pas
+                            send_message(ServerMessage::Notice(tr(language, MessageId::SpectatorCannotVote, &[])), &mut stream);
+                            continue;
+                        }
+                        if game.phase != GamePhase::Voting {
+                            // Si la phase de vote est terminée, les votes ne sont plus acceptés
+                            send_message(ServerMessage::Notice(tr(language, MessageId::VotingClosed, &[])), &mut stream);
+                            continue;
+                        }
+                        game.vote_difficulty(difficulty); // Enregistre le vote de difficulté
+                        let response = ServerMessage::DifficultyVote(difficulty);
+                        broadcast_message(response, &clients.lock().unwrap()); // Diffuse le vote aux autres clients
+                    },
+                    // Gestion des requêtes pour obtenir la liste des joueurs
+                    ClientMessage::RequestPlayers => {
+                        let game = game.lock().unwrap();
+                        send_message(player_list(&game), &mut stream);
+                    },
+                    // Gestion des requêtes pour obtenir le classement du match
+                    ClientMessage::RequestLeaderboard => {
+                        let game = game.lock().unwrap();
+                        send_message(ServerMessage::Leaderboard(game.standings()), &mut stream);
+                    },
+                    // Gestion des messages du chat
+                    ClientMessage::Chat(text) => {
+                        let mut game = game.lock().unwrap();
+                        match game.chat.check(&player_name, &text, Instant::now()) {
+                            Ok(text) => {
+                                let message = ServerMessage::ChatMessage { from: player_name.clone(), text, timestamp: timestamp() };
+                                broadcast_message(message, &clients.lock().unwrap());
+                            },
+                            Err(error) => send_message(ServerMessage::ChatRejected(error), &mut stream),
+                        }
+                    },
+                    // Gestion des messages privés : envoyés au destinataire, et à l'expéditeur pour qu'il les voie dans son historique
+                    ClientMessage::Whisper { to, text } => {
+                        let mut game = game.lock().unwrap();
+                        let clients = clients.lock().unwrap();
+                        let result = if clients.iter().any(|client| client.name == to) {
+                            game.chat.check(&player_name, &text, Instant::now())
+                        } else {
+                            Err(ChatError::UnknownRecipient)
+                        };
+                        match result {
+                            Ok(text) => {
+                                let message = ServerMessage::Whisper { from: player_name.clone(), to: to.clone(), text, timestamp: timestamp() };
+                                send_to(message.clone(), &clients, &to);
+                                if to != player_name {
+                                    send_message(message, &mut stream);
+                                }
+                            },
+                            Err(error) => send_message(ServerMessage::ChatRejected(error), &mut stream),
+                        }
+                    },
+                    // Dans le salon, un joueur se déclare prêt (ou ne l'est plus)
+                    ClientMessage::Ready => {
+                        let mut game = game.lock().unwrap();
+                        match game.toggle_ready(&player_name) {
+                            Ok(_) => broadcast_message(lobby_state(&game), &clients.lock().unwrap()),
+                            Err(error) => send_message(ServerMessage::Notice(lobby_error_text(language, error)), &mut stream),
+                        }
+                    },
+                    // L'hôte lance le match : la phase de vote commence
+                    ClientMessage::StartMatch => {
+                        let mut game = game.lock().unwrap();
+                        if let Err(error) = game.start_match(&player_name) {
+                            send_message(ServerMessage::Notice(lobby_error_text(language, error)), &mut stream);
+                        }
+                    },
+                    // Commandes d'administration : seul l'hôte a le droit de les utiliser
+                    ClientMessage::Admin(command) => {
+                        let mut game = game.lock().unwrap();
+                        let clients = clients.lock().unwrap();
+                        let result = admin::authorize(&game, &player_name).and_then(|_| admin::execute(command, Some(&player_name), &mut game, &clients));
+                        if let Err(error) = result {
+                            send_message(ServerMessage::AdminRejected(error), &mut stream);
+                        }
+                    },
+                    // Si un client envoie un autre message de type Join, ou tente de changer de compte, il est ignoré
+                    ClientMessage::Join { .. } | ClientMessage::JoinAsSpectator { .. } | ClientMessage::Login { .. } | ClientMessage::Register { .. } => {},
+                }
+            },
+            Err(_) => {
+                eprintln!("Error reading from client.");
+                break;
+            }
+        }
+    }
+
+    {
+        // Supprime le client de la liste des clients actifs à la déconnexion
+        let mut clients = clients.lock().unwrap();
+        clients.retain(|client| client.address != address);
+    }
+
+    {
+        // Retire le joueur du jeu à sa déconnexion
+        let mut game = game.lock().unwrap();
+        let current_turn = game.current_turn().map(str::to_string);
+        game.remove_player(&player_name);
+        game.chat.forget(&player_name);
+        broadcast_message(player_list(&game), &clients.lock().unwrap()); // Informe les joueurs restants du départ
+        if game.in_lobby() {
+            broadcast_message(lobby_state(&game), &clients.lock().unwrap()); // L'hôte a peut-être changé
+        }
+        if current_turn.as_deref() == Some(player_name.as_str()) {
+            // C'était son tour : la main passe au joueur suivant
+            if let Some(player) = game.current_turn() {
+                broadcast_message(ServerMessage::Turn(player.to_string()), &clients.lock().unwrap());
+            }
+        }
+    }
+}
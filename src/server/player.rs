@@ -1,21 +1,27 @@
-//ce fichier player.rs a été créé pour encapsuler la logique des joueurs, mais qu'il n'a pas encore été intégré ou utilisé dans les autres parties du code pour l'instant.
+// player.rs encapsule la logique des joueurs : nom et score cumulé au fil des manches d'un match.
 
 #[derive(Debug)]
 pub struct Player {
     pub name: String,
     pub score: u32,
+    pub attempts: u32, // Nombre de tentatives pendant la manche en cours.
 }
 
 impl Player {
     // Crée un nouveau joueur avec un nom et un score initial de 0
     pub fn new(name: String) -> Player {
-        Player { name, score: 0 }
+        Player { name, score: 0, attempts: 0 }
     }
 
     // Incrémente le score du joueur
     pub fn increment_score(&mut self) {
         self.score += 1;
     }
+
+    // Incrémente le nombre de tentatives du joueur pour la manche en cours
+    pub fn increment_attempts(&mut self) {
+        self.attempts += 1;
+    }
 }
 
 // Tests unitaires pour le module player
@@ -41,4 +47,15 @@ mod tests {
         // Vérifie que le score est incrémenté de 1
         assert_eq!(player.score, 1);
     }
+
+    // Test pour incrémenter le nombre de tentatives d'un joueur
+    #[test]
+    fn test_increment_attempts() {
+        let mut player = Player::new("TestPlayer".to_string());
+        player.increment_attempts();
+        player.increment_attempts();
+        // Vérifie que les tentatives sont comptées sans toucher au score
+        assert_eq!(player.attempts, 2);
+        assert_eq!(player.score, 0);
+    }
 }
@@ -0,0 +1,85 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+// `ShutdownSignal` coordonne l'arrêt du serveur entre les threads : il est demandé par un signal (SIGINT, SIGTERM)
+// ou par la console, puis le minuteur termine la partie et indique que l'arrêt est terminé.
+#[derive(Default)]
+pub struct ShutdownSignal {
+    requested: AtomicBool, // L'arrêt a été demandé : le serveur n'accepte plus de connexions.
+    reason: Mutex<Option<String>>, // Raison de l'arrêt annoncée aux joueurs.
+    finished: AtomicBool, // La partie est terminée : les connexions restantes peuvent être fermées.
+}
+
+impl ShutdownSignal {
+    pub fn new() -> ShutdownSignal {
+        ShutdownSignal::default()
+    }
+
+    // Demande l'arrêt du serveur. Renvoie `false` si l'arrêt avait déjà été demandé (la première raison est conservée).
+    pub fn request(&self, reason: Option<String>) -> bool {
+        let mut current = self.reason.lock().unwrap();
+        if self.requested.swap(true, Ordering::SeqCst) {
+            return false;
+        }
+        *current = reason;
+        true
+    }
+
+    // Indique si l'arrêt du serveur a été demandé.
+    pub fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+
+    // Renvoie la raison de l'arrêt.
+    pub fn reason(&self) -> Option<String> {
+        self.reason.lock().unwrap().clone()
+    }
+
+    // Indique que la partie est terminée.
+    pub fn finish(&self) {
+        self.finished.store(true, Ordering::SeqCst);
+    }
+
+    // Indique si la partie est terminée.
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::SeqCst)
+    }
+}
+
+// Enregistre le classement dans un fichier, une ligne par joueur (nom et score séparés par une tabulation).
+pub fn save_leaderboard(path: &Path, standings: &[(String, u32)]) -> io::Result<()> {
+    let lines: String = standings.iter().map(|(name, score)| format!("{}\t{}\n", name, score)).collect();
+    fs::write(path, lines)
+}
+
+// Tests unitaires pour le module shutdown
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour la demande d'arrêt
+    #[test]
+    fn test_request() {
+        let signal = ShutdownSignal::new();
+        assert!(!signal.is_requested());
+        assert!(signal.request(Some("maintenance".to_string())));
+        assert!(!signal.request(None));
+        assert!(signal.is_requested());
+        assert_eq!(signal.reason(), Some("maintenance".to_string()));
+        assert!(!signal.is_finished());
+        signal.finish();
+        assert!(signal.is_finished());
+    }
+
+    // Test pour l'enregistrement du classement
+    #[test]
+    fn test_save_leaderboard() {
+        let path = std::env::temp_dir().join(format!("number_game_leaderboard_{}.txt", std::process::id()));
+        save_leaderboard(&path, &[("Alice".to_string(), 2), ("Bob".to_string(), 1)]).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Alice\t2\nBob\t1\n");
+        fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,48 @@
+use std::net::SocketAddr;
+use crate::codec::write_message;
+use crate::protocol::ServerMessage;
+use crate::transport::Stream;
+
+// `Connection` représente un client connecté qui a rejoint la partie.
+pub struct Connection {
+    pub name: String, // Nom du joueur.
+    pub address: Option<SocketAddr>, // Adresse du client, qui identifie la connexion.
+    pub stream: Stream, // Flux utilisé pour envoyer les messages au client.
+}
+
+// `broadcast_message` envoie un message à tous les clients connectés.
+// Une erreur d'écriture signifie que le client s'est déconnecté : il est retiré par son propre thread, on l'ignore ici.
+pub fn broadcast_message(message: ServerMessage, clients: &[Connection]) {
+    for client in clients.iter() {
+        write_message(&mut &client.stream, &message).ok();
+    }
+}
+
+// `broadcast_except` envoie un message à tous les clients connectés, sauf à celui qui est à l'origine du message.
+pub fn broadcast_except(message: ServerMessage, clients: &[Connection], sender: Option<SocketAddr>) {
+    for client in clients.iter().filter(|client| client.address != sender) {
+        write_message(&mut &client.stream, &message).ok();
+    }
+}
+
+// `send_to` envoie un message au joueur qui porte le nom donné, et indique s'il est connecté.
+pub fn send_to(message: ServerMessage, clients: &[Connection], name: &str) -> bool {
+    let mut found = false;
+    for client in clients.iter().filter(|client| client.name == name) {
+        write_message(&mut &client.stream, &message).ok();
+        found = true;
+    }
+    found
+}
+
+// `send_to_spectators` envoie un message aux seuls spectateurs (par exemple le nombre secret à la fin d'une manche).
+pub fn send_to_spectators(message: ServerMessage, clients: &[Connection], spectators: &[String]) {
+    for client in clients.iter().filter(|client| spectators.contains(&client.name)) {
+        write_message(&mut &client.stream, &message).ok();
+    }
+}
+
+// `send_message` envoie un message à un seul client (par exemple un indice ou une erreur qui ne concerne que lui).
+pub fn send_message(message: ServerMessage, stream: &mut Stream) {
+    write_message(stream, &message).ok();
+}
@@ -0,0 +1,86 @@
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::sync::Arc;
+use rcgen::{BasicConstraints, CertificateParams, DnType, IsCa, KeyPair, KeyUsagePurpose};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::{ClientConfig, RootCertStore, ServerConfig};
+
+// Certificats générés pour tester TLS en local (au format PEM) : une autorité de certification auto-signée,
+// et le certificat du serveur signé par cette autorité, avec sa clé privée.
+pub struct Certificates {
+    pub ca: String, // Certificat de l'autorité, à donner aux clients (`--ca`).
+    pub cert: String, // Certificat du serveur (TLS_CERT).
+    pub key: String, // Clé privée du serveur (TLS_KEY).
+}
+
+// Configuration TLS du serveur, lue depuis les fichiers PEM du certificat (suivi éventuellement des certificats
+// intermédiaires) et de la clé privée.
+pub fn server_config(cert: &Path, key: &Path) -> io::Result<Arc<ServerConfig>> {
+    server_config_from_pem(&fs::read_to_string(cert)?, &fs::read_to_string(key)?)
+}
+
+// Configuration TLS du serveur à partir du contenu PEM du certificat et de la clé privée.
+pub fn server_config_from_pem(cert: &str, key: &str) -> io::Result<Arc<ServerConfig>> {
+    let chain = CertificateDer::pem_slice_iter(cert.as_bytes()).collect::<Result<Vec<_>, _>>().map_err(invalid_data)?;
+    let key = PrivateKeyDer::from_pem_slice(key.as_bytes()).map_err(invalid_data)?;
+    let config = ServerConfig::builder().with_no_client_auth().with_single_cert(chain, key).map_err(invalid_data)?;
+    Ok(Arc::new(config))
+}
+
+// Configuration TLS du client, qui fait confiance aux autorités du fichier PEM donné (par exemple une autorité auto-signée).
+pub fn client_config(ca: &Path) -> io::Result<Arc<ClientConfig>> {
+    client_config_from_pem(&fs::read_to_string(ca)?)
+}
+
+// Configuration TLS du client à partir du contenu PEM des certificats des autorités de confiance.
+pub fn client_config_from_pem(ca: &str) -> io::Result<Arc<ClientConfig>> {
+    let mut roots = RootCertStore::empty();
+    for cert in CertificateDer::pem_slice_iter(ca.as_bytes()) {
+        roots.add(cert.map_err(invalid_data)?).map_err(invalid_data)?;
+    }
+    if roots.is_empty() {
+        return Err(io::Error::new(ErrorKind::InvalidData, "no certificate found"));
+    }
+    Ok(Arc::new(ClientConfig::builder().with_root_certificates(roots).with_no_client_auth()))
+}
+
+// Génère une autorité de certification et un certificat de serveur valable pour les noms d'hôte
+// ou les adresses IP donnés.
+pub fn generate_certificates(names: &[String]) -> Result<Certificates, rcgen::Error> {
+    let mut ca_params = CertificateParams::default();
+    ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    ca_params.distinguished_name.push(DnType::CommonName, "Number Game local CA");
+    ca_params.key_usages = vec![KeyUsagePurpose::KeyCertSign, KeyUsagePurpose::CrlSign];
+    let ca_key = KeyPair::generate()?;
+    let ca = ca_params.self_signed(&ca_key)?;
+
+    let mut params = CertificateParams::new(names.to_vec())?; // Les adresses IP sont reconnues comme telles
+    params.distinguished_name.push(DnType::CommonName, "Number Game server");
+    let key = KeyPair::generate()?;
+    let cert = params.signed_by(&key, &ca, &ca_key)?;
+    Ok(Certificates { ca: ca.pem(), cert: cert.pem(), key: key.serialize_pem() })
+}
+
+fn invalid_data(error: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, error)
+}
+
+// Tests unitaires pour le module tls
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test pour la génération des certificats et la lecture des configurations
+    #[test]
+    fn test_generate_and_load() {
+        let certificates = generate_certificates(&["localhost".to_string(), "127.0.0.1".to_string()]).unwrap();
+        assert!(certificates.ca.starts_with("-----BEGIN CERTIFICATE-----"));
+        assert!(server_config_from_pem(&certificates.cert, &certificates.key).is_ok());
+        assert!(client_config_from_pem(&certificates.ca).is_ok());
+        assert_eq!(client_config_from_pem("").unwrap_err().kind(), ErrorKind::InvalidData);
+        assert!(server_config_from_pem(&certificates.cert, &certificates.cert).is_err()); // Pas de clé privée
+        assert_eq!(client_config(Path::new("/nonexistent/ca.pem")).unwrap_err().kind(), ErrorKind::NotFound);
+    }
+}
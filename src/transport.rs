@@ -0,0 +1,207 @@
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpStream};
+use std::sync::{Arc, Mutex, MutexGuard};
+use rustls::pki_types::ServerName;
+use rustls::{ClientConfig, ClientConnection, Connection, ServerConfig, ServerConnection};
+
+// `Stream` est une connexion entre le client et le serveur, en clair ou chiffrée avec TLS.
+// Comme un `TcpStream`, elle peut être clonée pour être lue par un thread et écrite par d'autres :
+// les clones partagent l'état TLS de la connexion. La lecture du réseau se fait sans garder cet état verrouillé,
+// pour qu'un thread bloqué en lecture n'empêche pas les autres d'écrire.
+pub struct Stream {
+    tcp: TcpStream, // Connexion TCP sous-jacente.
+    tls: Option<Arc<Mutex<Connection>>>, // État TLS partagé entre les clones (None pour une connexion en clair).
+}
+
+impl Stream {
+    // Connexion en clair.
+    pub fn plain(tcp: TcpStream) -> Stream {
+        Stream { tcp, tls: None }
+    }
+
+    // Connexion TLS acceptée par le serveur. La poignée de main a lieu avec `handshake` ou lors de la première lecture.
+    pub fn server(tcp: TcpStream, config: Arc<ServerConfig>) -> io::Result<Stream> {
+        let connection = ServerConnection::new(config).map_err(io::Error::other)?;
+        Ok(Stream { tcp, tls: Some(Arc::new(Mutex::new(connection.into()))) })
+    }
+
+    // Connexion TLS ouverte par le client vers le serveur portant ce nom (nom d'hôte ou adresse IP du certificat).
+    pub fn client(tcp: TcpStream, config: Arc<ClientConfig>, server_name: &str) -> io::Result<Stream> {
+        let name = ServerName::try_from(server_name.to_string()).map_err(|error| io::Error::new(ErrorKind::InvalidInput, error))?;
+        let connection = ClientConnection::new(config, name).map_err(io::Error::other)?;
+        Ok(Stream { tcp, tls: Some(Arc::new(Mutex::new(connection.into()))) })
+    }
+
+    // Indique si la connexion est chiffrée.
+    pub fn is_tls(&self) -> bool {
+        self.tls.is_some()
+    }
+
+    // Crée un autre accès à la même connexion.
+    pub fn try_clone(&self) -> io::Result<Stream> {
+        Ok(Stream { tcp: self.tcp.try_clone()?, tls: self.tls.clone() })
+    }
+
+    // Adresse de l'autre extrémité de la connexion.
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.tcp.peer_addr()
+    }
+
+    // Termine la poignée de main TLS (vérification du certificat comprise). Sans TLS, ne fait rien.
+    pub fn handshake(&self) -> io::Result<()> {
+        let Some(tls) = &self.tls else { return Ok(()) };
+        loop {
+            {
+                let mut connection = tls.lock().unwrap();
+                send_tls(&mut connection, &self.tcp)?;
+                if !connection.is_handshaking() {
+                    return Ok(());
+                }
+            }
+            if self.receive(tls)? == 0 {
+                return Err(io::Error::new(ErrorKind::UnexpectedEof, "connection closed during the TLS handshake"));
+            }
+        }
+    }
+
+    // Ferme la connexion. Avec TLS, l'autre extrémité est d'abord prévenue de la fermeture.
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        if let Some(tls) = &self.tls {
+            let mut connection = tls.lock().unwrap();
+            connection.send_close_notify();
+            send_tls(&mut connection, &self.tcp).ok(); // La connexion est peut-être déjà fermée
+        }
+        self.tcp.shutdown(how)
+    }
+
+    // Lit des données chiffrées sur le réseau et les transmet à TLS. Renvoie le nombre d'octets lus (0 à la fin du flux).
+    fn receive(&self, tls: &Mutex<Connection>) -> io::Result<usize> {
+        let mut received = [0; 4096];
+        let count = (&self.tcp).read(&mut received)?; // Sans verrou : les autres threads peuvent écrire pendant l'attente
+        let mut connection = tls.lock().unwrap();
+        let mut data = &received[..count];
+        loop {
+            connection.read_tls(&mut data)?; // Un flux vide indique à TLS que la connexion est fermée
+            if let Err(error) = connection.process_new_packets() {
+                send_tls(&mut connection, &self.tcp).ok(); // Prévient l'autre extrémité de l'erreur
+                return Err(io::Error::new(ErrorKind::InvalidData, error));
+            }
+            if data.is_empty() {
+                break;
+            }
+        }
+        send_tls(&mut connection, &self.tcp)?; // Réponses de la poignée de main
+        Ok(count)
+    }
+}
+
+// Envoie sur le réseau les données chiffrées en attente.
+fn send_tls(connection: &mut MutexGuard<Connection>, mut tcp: &TcpStream) -> io::Result<()> {
+    while connection.wants_write() {
+        connection.write_tls(&mut tcp)?;
+    }
+    Ok(())
+}
+
+impl Read for &Stream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let Some(tls) = &self.tls else { return (&self.tcp).read(buf) };
+        loop {
+            match tls.lock().unwrap().reader().read(buf) {
+                Err(error) if error.kind() == ErrorKind::WouldBlock => {}, // Aucune donnée déchiffrée en attente
+                result => return result,
+            }
+            self.receive(tls)?;
+        }
+    }
+}
+
+impl Write for &Stream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let Some(tls) = &self.tls else { return (&self.tcp).write(buf) };
+        let mut connection = tls.lock().unwrap();
+        let count = connection.writer().write(buf)?;
+        send_tls(&mut connection, &self.tcp)?;
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &self.tls {
+            Some(tls) => send_tls(&mut tls.lock().unwrap(), &self.tcp),
+            None => (&self.tcp).flush(),
+        }
+    }
+}
+
+impl Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        (&*self).read(buf)
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        (&*self).write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        (&*self).flush()
+    }
+}
+
+// Tests unitaires pour le module transport
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+    use crate::codec::{read_message, write_message};
+    use crate::tls::{client_config_from_pem, generate_certificates, server_config_from_pem};
+
+    // Ouvre une connexion locale et renvoie ses deux extrémités (client, serveur).
+    fn tcp_pair() -> (TcpStream, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server, _) = listener.accept().unwrap();
+        (client, server)
+    }
+
+    // Test pour l'échange de messages sur une connexion TLS, lue et écrite depuis des threads différents
+    #[test]
+    fn test_tls_round_trip() {
+        let certificates = generate_certificates(&["localhost".to_string()]).unwrap();
+        let (client, server) = tcp_pair();
+        let server = Stream::server(server, server_config_from_pem(&certificates.cert, &certificates.key).unwrap()).unwrap();
+        let client = Stream::client(client, client_config_from_pem(&certificates.ca).unwrap(), "localhost").unwrap();
+        assert!(client.is_tls());
+
+        let echo = thread::spawn(move || {
+            // Le serveur renvoie chaque message reçu, depuis un clone de la connexion
+            let writer = server.try_clone().unwrap();
+            let mut reader = server;
+            while let Some(message) = read_message::<String>(&mut reader).unwrap() {
+                write_message(&mut &writer, &message).unwrap();
+            }
+        });
+        client.handshake().unwrap();
+        for text in ["hello", "world"] {
+            write_message(&mut &client, &text.to_string()).unwrap();
+            assert_eq!(read_message::<String>(&mut &client).unwrap(), Some(text.to_string()));
+        }
+        client.shutdown(Shutdown::Both).unwrap();
+        echo.join().unwrap();
+    }
+
+    // Test pour le refus d'un certificat signé par une autre autorité
+    #[test]
+    fn test_untrusted_certificate() {
+        let certificates = generate_certificates(&["localhost".to_string()]).unwrap();
+        let other = generate_certificates(&["localhost".to_string()]).unwrap();
+        let (client, server) = tcp_pair();
+        let server = Stream::server(server, server_config_from_pem(&certificates.cert, &certificates.key).unwrap()).unwrap();
+        let client = Stream::client(client, client_config_from_pem(&other.ca).unwrap(), "localhost").unwrap();
+        let handle = thread::spawn(move || server.handshake());
+        assert_eq!(client.handshake().unwrap_err().kind(), ErrorKind::InvalidData);
+        assert!(handle.join().unwrap().is_err());
+    }
+}
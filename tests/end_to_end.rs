@@ -0,0 +1,568 @@
+// Tests de bout en bout : un serveur est lancé dans le processus sur un port choisi par le système,
+// et des clients scriptés jouent des parties complètes à travers le protocole réseau.
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+use number_game::game_client::GameClient;
+use number_game::protocol::{AdminCommand, AdminError, AuthError, ChatError, Difficulty, GamePhase, GuessError, Hint, Language, ServerMessage};
+use number_game::range::KnownRange;
+use number_game::tls::{client_config_from_pem, generate_certificates};
+use number_game::server::{self, config::ServerConfig, game::{GameMode, HintVisibility, LateJoinPolicy}, shutdown::ShutdownSignal};
+
+const TIMEOUT: Duration = Duration::from_secs(5); // Temps maximal d'attente d'un message.
+
+// Lance un serveur avec la configuration donnée et renvoie son adresse.
+fn start_server(config: ServerConfig) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || server::serve(listener, config));
+    address
+}
+
+// Configuration courte pour les tests : sans salon d'attente ni console, deux secondes de vote, une seule manche.
+fn test_config() -> ServerConfig {
+    ServerConfig { lobby: false, console: false, voting_duration: Duration::from_secs(2), rounds_per_match: 1, ..ServerConfig::default() }
+}
+
+// Connecte un client et rejoint la partie.
+fn join(address: SocketAddr, name: &str) -> GameClient {
+    let client = GameClient::connect(address).unwrap();
+    client.join(name, Language::English).unwrap();
+    client
+}
+
+// Renvoie le prochain message reçu par un client, en ignorant les décomptes envoyés chaque seconde,
+// dont le nombre dépend de l'instant exact où le minuteur du serveur se déclenche.
+fn next(client: &GameClient) -> ServerMessage {
+    loop {
+        match client.next_event(TIMEOUT) {
+            Some(ServerMessage::VotingCountdown(_)) | Some(ServerMessage::TimeRemaining(_)) => continue,
+            Some(message) => return message,
+            None => panic!("no message received"),
+        }
+    }
+}
+
+// Vérifie que les prochains messages reçus par un client sont exactement ceux attendus, dans l'ordre.
+fn expect(client: &GameClient, expected: &[ServerMessage]) {
+    for message in expected {
+        assert_eq!(next(client), *message);
+    }
+}
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+// Liste des joueurs donnés, sans spectateur.
+fn players(players: &[&str]) -> ServerMessage {
+    ServerMessage::PlayerList { players: names(players), spectators: vec![], waiting: vec![] }
+}
+
+// Vérifie le prochain message reçu : l'état de la partie envoyé à un client arrivé en cours de partie.
+// Le temps restant dépend de l'instant exact de l'arrivée : seule sa présence est vérifiée.
+fn expect_state(client: &GameClient, expected_phase: GamePhase, expected_difficulty: Option<Difficulty>) {
+    match next(client) {
+        ServerMessage::GameState { phase, difficulty, round: 1, remaining: Some(_), .. } if phase == expected_phase && difficulty == expected_difficulty => {},
+        message => panic!("expected the game state, got {:?}", message),
+    }
+}
+
+// Rejoint la partie avec Alice puis Bob, et vérifie les messages d'arrivée.
+fn join_alice_and_bob(address: SocketAddr) -> (GameClient, GameClient) {
+    let alice = join(address, "Alice");
+    expect(&alice, &[ServerMessage::Notice("Welcome Alice!".to_string()), players(&["Alice"])]);
+    let bob = join(address, "Bob");
+    expect(&bob, &[ServerMessage::Notice("Welcome Bob!".to_string())]);
+    expect_state(&bob, GamePhase::Voting, None);
+    expect(&bob, &[players(&["Alice", "Bob"])]);
+    expect(&alice, &[players(&["Alice", "Bob"])]);
+    (alice, bob)
+}
+
+// Alice et Bob votent pour une difficulté ; chaque vote est diffusé aux deux joueurs.
+fn vote(alice: &GameClient, bob: &GameClient, difficulty: Difficulty) {
+    for voter in [alice, bob] {
+        voter.vote(difficulty).unwrap();
+        expect(alice, &[ServerMessage::DifficultyVote(difficulty)]);
+        expect(bob, &[ServerMessage::DifficultyVote(difficulty)]);
+    }
+}
+
+// Test d'un match complet en mode simultané avec indices partagés : rejoindre, voter, jouer, gagner
+#[test]
+fn test_simultaneous_match() {
+    let address = start_server(ServerConfig { hint_visibility: HintVisibility::Shared, ..test_config() });
+    let (alice, bob) = join_alice_and_bob(address);
+
+    // Pendant la phase de vote, les devinettes sont refusées.
+    alice.guess(50).unwrap();
+    expect(&alice, &[ServerMessage::GuessRejected(GuessError::WrongPhase)]);
+
+    vote(&alice, &bob, Difficulty::Easy);
+    for client in [&alice, &bob] {
+        expect(client, &[ServerMessage::GameStart(Difficulty::Easy), ServerMessage::TimerStart(120)]);
+    }
+
+    // Bob propose un nombre hors de la plage : il reçoit son indice, Alice voit la devinette et l'indice.
+    bob.guess(0).unwrap();
+    expect(&bob, &[ServerMessage::Hint(vec![Hint::Higher])]);
+    expect(&alice, &[ServerMessage::GuessWithHint(0, "Bob".to_string(), vec![Hint::Higher])]);
+
+    // Alice trouve le nombre par recherche dichotomique.
+    let mut range = KnownRange::new(Difficulty::Easy.max_number());
+    let mut attempts = 0;
+    loop {
+        let guess = range.optimal_guess();
+        attempts += 1;
+        alice.guess(guess).unwrap();
+        let hints = match next(&alice) {
+            ServerMessage::Hint(hints) => hints,
+            message => panic!("expected a hint, got {:?}", message),
+        };
+        expect(&bob, &[ServerMessage::GuessWithHint(guess, "Alice".to_string(), hints.clone())]);
+        if hints == [Hint::Correct] {
+            break;
+        }
+        range.apply(guess, &hints);
+        assert!(attempts < 7, "binary search should find the number in 7 attempts");
+    }
+
+    let standings = vec![("Alice".to_string(), 1), ("Bob".to_string(), 0)];
+    for client in [&alice, &bob] {
+        expect(client, &[
+            ServerMessage::PlayerWon("Alice".to_string()),
+            ServerMessage::GameEnd,
+            ServerMessage::Standings { round: 1, rounds: 1, standings: standings.clone() },
+            ServerMessage::MatchEnd { standings: standings.clone(), winner: Some("Alice".to_string()) },
+        ]);
+    }
+
+    // Un nouveau match commence : les scores sont remis à zéro.
+    alice.request_leaderboard().unwrap();
+    expect(&alice, &[ServerMessage::Leaderboard(vec![("Alice".to_string(), 0), ("Bob".to_string(), 0)])]);
+}
+
+// Test du mode tour par tour : seul le joueur dont c'est le tour peut deviner, puis la main passe à l'autre
+#[test]
+fn test_turn_based_round() {
+    let address = start_server(ServerConfig { mode: GameMode::TurnBased, round_duration: None, ..test_config() });
+    let (alice, bob) = join_alice_and_bob(address);
+    vote(&alice, &bob, Difficulty::Hard);
+
+    expect(&alice, &[ServerMessage::GameStart(Difficulty::Hard)]);
+    let first = match next(&alice) {
+        ServerMessage::Turn(player) => player,
+        message => panic!("expected a turn announcement, got {:?}", message),
+    };
+    expect(&bob, &[ServerMessage::GameStart(Difficulty::Hard), ServerMessage::Turn(first.clone())]);
+    let (current, waiting, other) = if first == "Alice" { (&alice, &bob, "Bob") } else { (&bob, &alice, "Alice") };
+
+    waiting.guess(500).unwrap();
+    expect(waiting, &[ServerMessage::GuessRejected(GuessError::NotYourTurn)]);
+
+    current.guess(0).unwrap();
+    expect(current, &[ServerMessage::Hint(vec![Hint::Higher]), ServerMessage::Turn(other.to_string())]);
+    expect(waiting, &[ServerMessage::Guess(0, first.clone()), ServerMessage::Turn(other.to_string())]);
+}
+
+// Test des requêtes et du départ d'un joueur
+#[test]
+fn test_requests_and_disconnect() {
+    let address = start_server(test_config());
+    let (alice, bob) = join_alice_and_bob(address);
+
+    bob.request_players().unwrap();
+    expect(&bob, &[players(&["Alice", "Bob"])]);
+    bob.request_leaderboard().unwrap();
+    expect(&bob, &[ServerMessage::Leaderboard(vec![("Alice".to_string(), 0), ("Bob".to_string(), 0)])]);
+
+    bob.close();
+    expect(&alice, &[players(&["Alice"])]);
+}
+
+// Test du chat : diffusion, messages privés, filtre de mots interdits et limite de débit
+#[test]
+fn test_chat() {
+    let address = start_server(ServerConfig {
+        chat_rate_limit: 3,
+        chat_blocked_words: vec!["darn".to_string()],
+        ..test_config()
+    });
+    let (alice, bob) = join_alice_and_bob(address);
+
+    alice.chat("hello darn world").unwrap();
+    for client in [&alice, &bob] {
+        match next(client) {
+            ServerMessage::ChatMessage { from, text, .. } => assert_eq!((from.as_str(), text.as_str()), ("Alice", "hello **** world")),
+            message => panic!("expected a chat message, got {:?}", message),
+        }
+    }
+
+    bob.whisper("Alice", "psst").unwrap();
+    for client in [&alice, &bob] {
+        match next(client) {
+            ServerMessage::Whisper { from, to, text, .. } => assert_eq!((from.as_str(), to.as_str(), text.as_str()), ("Bob", "Alice", "psst")),
+            message => panic!("expected a whisper, got {:?}", message),
+        }
+    }
+    bob.whisper("Carol", "psst").unwrap();
+    expect(&bob, &[ServerMessage::ChatRejected(ChatError::UnknownRecipient)]);
+
+    // Bob a déjà envoyé un message : les deux suivants passent, le troisième dépasse la limite.
+    for _ in 0..2 {
+        bob.chat("spam").unwrap();
+        assert!(matches!(next(&bob), ServerMessage::ChatMessage { .. }));
+    }
+    bob.chat("spam").unwrap();
+    expect(&bob, &[ServerMessage::ChatRejected(ChatError::RateLimited)]);
+}
+
+// Test du mode spectateur : il reçoit les diffusions et le nombre secret, sans pouvoir voter ni deviner
+#[test]
+fn test_spectator() {
+    let address = start_server(ServerConfig { hint_visibility: HintVisibility::Shared, ..test_config() });
+    let (alice, bob) = join_alice_and_bob(address);
+    let zoe = GameClient::connect(address).unwrap();
+    zoe.join_as_spectator("Zoe", Language::English).unwrap();
+    let list = ServerMessage::PlayerList { players: names(&["Alice", "Bob"]), spectators: names(&["Zoe"]), waiting: vec![] };
+    expect(&zoe, &[ServerMessage::Notice("Welcome Zoe!".to_string())]);
+    expect_state(&zoe, GamePhase::Voting, None);
+    expect(&zoe, std::slice::from_ref(&list));
+    for client in [&alice, &bob] {
+        expect(client, std::slice::from_ref(&list));
+    }
+
+    zoe.vote(Difficulty::Hard).unwrap();
+    expect(&zoe, &[ServerMessage::Notice("Spectators cannot vote.".to_string())]);
+    vote(&alice, &bob, Difficulty::Easy);
+    expect(&zoe, &[ServerMessage::DifficultyVote(Difficulty::Easy), ServerMessage::DifficultyVote(Difficulty::Easy)]);
+    for client in [&zoe, &alice, &bob] {
+        expect(client, &[ServerMessage::GameStart(Difficulty::Easy), ServerMessage::TimerStart(120)]);
+    }
+    zoe.guess(50).unwrap();
+    expect(&zoe, &[ServerMessage::GuessRejected(GuessError::Spectating)]);
+
+    // Alice trouve le nombre : le spectateur voit chaque devinette, puis le nombre secret.
+    let mut range = KnownRange::new(Difficulty::Easy.max_number());
+    let secret = loop {
+        let guess = range.optimal_guess();
+        alice.guess(guess).unwrap();
+        let hints = match next(&zoe) {
+            ServerMessage::GuessWithHint(value, player, hints) if value == guess && player == "Alice" => hints,
+            message => panic!("expected Alice's guess, got {:?}", message),
+        };
+        if hints == [Hint::Correct] {
+            break guess;
+        }
+        range.apply(guess, &hints);
+    };
+    expect(&zoe, &[ServerMessage::PlayerWon("Alice".to_string()), ServerMessage::SecretRevealed(secret), ServerMessage::GameEnd]);
+    match next(&zoe) {
+        ServerMessage::Standings { standings, .. } => assert_eq!(standings, vec![("Alice".to_string(), 1), ("Bob".to_string(), 0)]),
+        message => panic!("expected the standings, got {:?}", message),
+    }
+}
+
+// Test de l'arrivée en cours de manche : le retardataire regarde la manche en spectateur, puis joue à la suivante
+#[test]
+fn test_late_join_spectate() {
+    let address = start_server(ServerConfig { late_join: LateJoinPolicy::Spectate, rounds_per_match: 2, ..test_config() });
+    let (alice, bob) = join_alice_and_bob(address);
+    vote(&alice, &bob, Difficulty::Easy);
+    for client in [&alice, &bob] {
+        expect(client, &[ServerMessage::GameStart(Difficulty::Easy), ServerMessage::TimerStart(120)]);
+    }
+
+    let carol = join(address, "Carol");
+    expect(&carol, &[ServerMessage::Notice("Welcome Carol!".to_string())]);
+    match next(&carol) {
+        ServerMessage::GameState { phase: GamePhase::Playing, difficulty: Some(Difficulty::Easy), round: 1, rounds: 2, remaining: Some(_) } => {},
+        message => panic!("expected the game state, got {:?}", message),
+    }
+    let watching = ServerMessage::PlayerList { players: names(&["Alice", "Bob"]), spectators: names(&["Carol"]), waiting: names(&["Carol"]) };
+    expect(&carol, &[
+        ServerMessage::Notice("A round is in progress: you are watching it as a spectator and will play from the next round.".to_string()),
+        watching.clone(),
+    ]);
+    for client in [&alice, &bob] {
+        expect(client, std::slice::from_ref(&watching));
+    }
+    carol.guess(50).unwrap();
+    expect(&carol, &[ServerMessage::GuessRejected(GuessError::NextRound)]);
+
+    // Alice propose les nombres dans l'ordre jusqu'à trouver le bon ; Carol entre dans la partie à la fin de la manche.
+    let mut guess = 0;
+    loop {
+        guess += 1;
+        alice.guess(guess).unwrap();
+        if next(&alice) == ServerMessage::Hint(vec![Hint::Correct]) {
+            break;
+        }
+        next(&carol); // La devinette d'Alice.
+    }
+    next(&carol); // La devinette gagnante d'Alice.
+    expect(&carol, &[
+        ServerMessage::PlayerWon("Alice".to_string()),
+        ServerMessage::SecretRevealed(guess),
+        ServerMessage::GameEnd,
+        players(&["Alice", "Bob", "Carol"]),
+    ]);
+    match next(&carol) {
+        ServerMessage::Standings { round: 1, rounds: 2, standings } => assert_eq!(standings.len(), 3),
+        message => panic!("expected the standings, got {:?}", message),
+    }
+    carol.vote(Difficulty::Easy).unwrap();
+    expect(&carol, &[ServerMessage::DifficultyVote(Difficulty::Easy)]);
+}
+
+// Test de l'arrivée en cours de manche avec file d'attente : les autres joueurs voient le nouveau venu en attente
+#[test]
+fn test_late_join_queue() {
+    let address = start_server(ServerConfig { late_join: LateJoinPolicy::Queue, ..test_config() });
+    let (alice, bob) = join_alice_and_bob(address);
+    vote(&alice, &bob, Difficulty::Easy);
+    for client in [&alice, &bob] {
+        expect(client, &[ServerMessage::GameStart(Difficulty::Easy), ServerMessage::TimerStart(120)]);
+    }
+
+    let carol = join(address, "Carol");
+    expect(&carol, &[ServerMessage::Notice("Welcome Carol!".to_string())]);
+    expect_state(&carol, GamePhase::Playing, Some(Difficulty::Easy));
+    let queued = ServerMessage::PlayerList { players: names(&["Alice", "Bob"]), spectators: vec![], waiting: names(&["Carol"]) };
+    expect(&carol, &[
+        ServerMessage::Notice("A round is in progress: you will join the game at the next round.".to_string()),
+        queued.clone(),
+    ]);
+    for client in [&alice, &bob] {
+        expect(client, std::slice::from_ref(&queued));
+    }
+}
+
+// Test du salon d'attente : l'hôte ne peut pas lancer le match seul, puis le match démarre quand tout le monde est prêt
+#[test]
+fn test_lobby() {
+    let address = start_server(ServerConfig { lobby: true, min_players: 2, lobby_countdown: Duration::from_secs(1), ..test_config() });
+    let lobby = |ready: &[&str], countdown: Option<u32>| ServerMessage::Lobby { host: Some("Alice".to_string()), ready: names(ready), min_players: 2, countdown };
+
+    let alice = join(address, "Alice");
+    expect(&alice, &[ServerMessage::Notice("Welcome Alice!".to_string()), players(&["Alice"]), lobby(&[], None)]);
+    alice.start_match().unwrap();
+    expect(&alice, &[ServerMessage::Notice("There are not enough players to start the match.".to_string())]);
+    alice.ready().unwrap();
+    expect(&alice, &[lobby(&["Alice"], None)]);
+
+    let bob = join(address, "Bob");
+    expect(&bob, &[ServerMessage::Notice("Welcome Bob!".to_string()), players(&["Alice", "Bob"]), lobby(&["Alice"], None)]);
+    expect(&alice, &[players(&["Alice", "Bob"]), lobby(&["Alice"], None)]);
+    bob.start_match().unwrap();
+    expect(&bob, &[ServerMessage::Notice("Only the host can start the match.".to_string())]);
+
+    // Tout le monde est prêt : le match démarre après une seconde, et les votes sont acceptés.
+    bob.ready().unwrap();
+    for client in [&alice, &bob] {
+        expect(client, &[lobby(&["Alice", "Bob"], Some(1))]);
+    }
+    match bob.next_event(TIMEOUT) {
+        Some(ServerMessage::VotingCountdown(_)) => {},
+        message => panic!("expected the voting countdown, got {:?}", message),
+    }
+    bob.ready().unwrap();
+    expect(&bob, &[ServerMessage::Notice("The match has already started.".to_string())]);
+    bob.vote(Difficulty::Hard).unwrap();
+    expect(&bob, &[ServerMessage::DifficultyVote(Difficulty::Hard)]);
+}
+
+// Test des commandes d'administration : réservées à l'hôte, elles pilotent la partie et excluent les joueurs
+#[test]
+fn test_admin_commands() {
+    let address = start_server(ServerConfig { lobby: true, min_players: 2, ..test_config() });
+    let lobby = |ready: &[&str]| ServerMessage::Lobby { host: Some("Alice".to_string()), ready: names(ready), min_players: 2, countdown: None };
+    let action = |command: AdminCommand| ServerMessage::AdminAction { by: Some("Alice".to_string()), action: command };
+
+    let alice = join(address, "Alice");
+    expect(&alice, &[ServerMessage::Notice("Welcome Alice!".to_string()), players(&["Alice"]), lobby(&[])]);
+    let bob = join(address, "Bob");
+    expect(&bob, &[ServerMessage::Notice("Welcome Bob!".to_string()), players(&["Alice", "Bob"]), lobby(&[])]);
+    expect(&alice, &[players(&["Alice", "Bob"]), lobby(&[])]);
+
+    bob.admin(AdminCommand::ForceStart).unwrap();
+    expect(&bob, &[ServerMessage::AdminRejected(AdminError::NotHost)]);
+    alice.admin(AdminCommand::SkipVoting).unwrap();
+    expect(&alice, &[ServerMessage::AdminRejected(AdminError::WrongPhase)]);
+
+    // L'hôte impose la difficulté, lance le match sans attendre les joueurs prêts, puis écourte le vote.
+    for command in [AdminCommand::SetDifficulty(Difficulty::Hard), AdminCommand::ForceStart] {
+        alice.admin(command.clone()).unwrap();
+        for client in [&alice, &bob] {
+            expect(client, &[action(command.clone())]);
+        }
+    }
+    bob.vote(Difficulty::Easy).unwrap();
+    expect(&bob, &[ServerMessage::DifficultyVote(Difficulty::Easy)]);
+    expect(&alice, &[ServerMessage::DifficultyVote(Difficulty::Easy)]);
+    alice.admin(AdminCommand::SkipVoting).unwrap();
+    for client in [&alice, &bob] {
+        expect(client, &[action(AdminCommand::SkipVoting), ServerMessage::GameStart(Difficulty::Hard), ServerMessage::TimerStart(120)]);
+    }
+
+    // Bob est banni : sa connexion est fermée, et il ne peut plus revenir sous ce nom.
+    alice.admin(AdminCommand::Ban("Bob".to_string())).unwrap();
+    expect(&bob, &[action(AdminCommand::Ban("Bob".to_string()))]);
+    assert_eq!(bob.next_event(TIMEOUT), None);
+    expect(&alice, &[action(AdminCommand::Ban("Bob".to_string())), players(&["Alice"])]);
+    let bob = join(address, "Bob");
+    expect(&bob, &[ServerMessage::Notice("You are banned from this game.".to_string())]);
+    assert_eq!(bob.next_event(TIMEOUT), None);
+}
+
+// Test des noms déjà utilisés : un autre client ne peut pas rejoindre la partie, même en spectateur, sous le nom d'un joueur connecté
+#[test]
+fn test_name_in_use() {
+    let address = start_server(test_config());
+    let alice = join(address, "Alice");
+    expect(&alice, &[ServerMessage::Notice("Welcome Alice!".to_string()), players(&["Alice"])]);
+
+    let impostor = GameClient::connect(address).unwrap();
+    impostor.join_as_spectator("Alice", Language::English).unwrap();
+    expect(&impostor, &[ServerMessage::AuthRejected(AuthError::NameInUse)]);
+    assert_eq!(impostor.next_event(TIMEOUT), None);
+    let impostor = join(address, "Alice");
+    expect(&impostor, &[ServerMessage::AuthRejected(AuthError::NameInUse)]);
+    assert_eq!(impostor.next_event(TIMEOUT), None);
+    let impostor = join(address, "aLICE"); // Les majuscules ne changent pas le nom
+    expect(&impostor, &[ServerMessage::AuthRejected(AuthError::NameInUse)]);
+    assert_eq!(impostor.next_event(TIMEOUT), None);
+
+    // Alice reste une joueuse : la liste suivante ne contient ni spectateur ni doublon, et elle peut voter.
+    let bob = join(address, "Bob");
+    expect(&bob, &[ServerMessage::Notice("Welcome Bob!".to_string())]);
+    expect_state(&bob, GamePhase::Voting, None);
+    expect(&alice, &[players(&["Alice", "Bob"])]);
+    alice.vote(Difficulty::Easy).unwrap();
+    expect(&alice, &[ServerMessage::DifficultyVote(Difficulty::Easy)]);
+}
+
+// Test des comptes : un nom enregistré est réservé à son propriétaire, et les invités peuvent être refusés
+#[test]
+fn test_accounts() {
+    let address = start_server(ServerConfig { allow_guests: false, ..test_config() });
+
+    // Alice crée son compte et rejoint la partie sous son nom.
+    let alice = GameClient::connect(address).unwrap();
+    alice.register("Alice", "short").unwrap();
+    expect(&alice, &[ServerMessage::AuthRejected(AuthError::WeakPassword)]);
+    alice.register("Alice", "correct horse").unwrap();
+    expect(&alice, &[ServerMessage::Authenticated("Alice".to_string())]);
+    alice.join("Alice", Language::English).unwrap();
+    expect(&alice, &[ServerMessage::Notice("Welcome Alice!".to_string()), players(&["Alice"])]);
+    alice.close();
+
+    // Le nom d'Alice est réservé, et ce serveur n'accepte pas les invités.
+    let intruder = join(address, "Alice");
+    expect(&intruder, &[ServerMessage::AuthRejected(AuthError::NameReserved)]);
+    assert_eq!(intruder.next_event(TIMEOUT), None);
+    let guest = join(address, "Bob");
+    expect(&guest, &[ServerMessage::AuthRejected(AuthError::GuestsNotAllowed)]);
+    assert_eq!(guest.next_event(TIMEOUT), None);
+
+    // Alice revient en se connectant à son compte.
+    let alice = GameClient::connect(address).unwrap();
+    alice.login("Alice", "wrong password").unwrap();
+    expect(&alice, &[ServerMessage::AuthRejected(AuthError::WrongCredentials)]);
+    alice.login("Alice", "correct horse").unwrap();
+    expect(&alice, &[ServerMessage::Authenticated("Alice".to_string())]);
+    alice.join("Alice", Language::English).unwrap();
+    expect(&alice, &[ServerMessage::Notice("Welcome Alice!".to_string())]);
+}
+
+// Test de la fermeture de la connexion après trop de tentatives de connexion à un compte
+#[test]
+fn test_auth_attempts() {
+    let address = start_server(test_config());
+    let alice = GameClient::connect(address).unwrap();
+    alice.register("Alice", "correct horse").unwrap();
+    expect(&alice, &[ServerMessage::Authenticated("Alice".to_string())]);
+    alice.close();
+
+    // Chaque échec est refusé, puis le serveur ferme la connexion au cinquième.
+    let intruder = GameClient::connect(address).unwrap();
+    for _ in 1..5 {
+        intruder.login("Alice", "wrong password").unwrap();
+        expect(&intruder, &[ServerMessage::AuthRejected(AuthError::WrongCredentials)]);
+    }
+    intruder.login("Alice", "wrong password").unwrap();
+    expect(&intruder, &[ServerMessage::AuthRejected(AuthError::TooManyAttempts)]);
+    assert_eq!(intruder.next_event(TIMEOUT), None);
+}
+
+// Test des connexions chiffrées avec TLS, avec des certificats générés pour l'occasion
+#[test]
+fn test_tls() {
+    let dir = std::env::temp_dir().join(format!("number_game_tls_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let certificates = generate_certificates(&["localhost".to_string(), "127.0.0.1".to_string()]).unwrap();
+    std::fs::write(dir.join("cert.pem"), &certificates.cert).unwrap();
+    std::fs::write(dir.join("key.pem"), &certificates.key).unwrap();
+    let address = start_server(ServerConfig { tls_cert: Some(dir.join("cert.pem")), tls_key: Some(dir.join("key.pem")), ..test_config() });
+
+    // Un client qui fait confiance à l'autorité joue normalement, que le serveur soit désigné par son nom ou son adresse.
+    let trusted = client_config_from_pem(&certificates.ca).unwrap();
+    let alice = GameClient::connect_tls(address, "127.0.0.1", trusted.clone()).unwrap();
+    alice.join("Alice", Language::English).unwrap();
+    expect(&alice, &[ServerMessage::Notice("Welcome Alice!".to_string()), players(&["Alice"])]);
+    let bob = GameClient::connect_tls(address, "localhost", trusted).unwrap();
+    bob.join("Bob", Language::English).unwrap();
+    expect(&bob, &[ServerMessage::Notice("Welcome Bob!".to_string())]);
+    expect_state(&bob, GamePhase::Voting, None);
+    expect(&bob, &[players(&["Alice", "Bob"])]);
+    expect(&alice, &[players(&["Alice", "Bob"])]);
+
+    // Un certificat signé par une autre autorité est refusé, et un client sans TLS n'obtient aucune réponse.
+    let untrusted = client_config_from_pem(&generate_certificates(&["localhost".to_string()]).unwrap().ca).unwrap();
+    assert!(GameClient::connect_tls(address, "localhost", untrusted).is_err());
+    let plain = join(address, "Carol");
+    assert_eq!(plain.next_event(TIMEOUT), None);
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+// Test de l'arrêt du serveur pendant une manche : annonce aux joueurs, fin de la manche après le délai de grâce,
+// enregistrement du classement, fermeture des connexions et fin de tous les threads du serveur
+#[test]
+fn test_graceful_shutdown() {
+    let leaderboard = std::env::temp_dir().join(format!("number_game_shutdown_{}.txt", std::process::id()));
+    let config = ServerConfig { shutdown_grace: Duration::from_secs(1), leaderboard_file: Some(leaderboard.clone()), ..test_config() };
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let signal = Arc::new(ShutdownSignal::new());
+    let (stopped, server_stopped) = mpsc::channel();
+    let server_signal = Arc::clone(&signal);
+    thread::spawn(move || {
+        server::serve_until(listener, config, &server_signal);
+        stopped.send(()).unwrap();
+    });
+
+    let (alice, bob) = join_alice_and_bob(address);
+    for client in [&alice, &bob] {
+        expect(client, &[ServerMessage::GameStart(Difficulty::Easy), ServerMessage::TimerStart(120)]);
+    }
+    assert!(signal.request(Some("maintenance".to_string())));
+    for client in [&alice, &bob] {
+        expect(client, &[ServerMessage::ServerShutdown { reason: Some("maintenance".to_string()), seconds: 1 }]);
+        // Le délai de grâce est écoulé : la manche est arrêtée et le nombre secret révélé.
+        assert!(matches!(next(client), ServerMessage::RoundTimeout { .. }));
+        expect(client, &[
+            ServerMessage::GameEnd,
+            ServerMessage::Standings { round: 1, rounds: 1, standings: vec![("Alice".to_string(), 0), ("Bob".to_string(), 0)] },
+            ServerMessage::MatchEnd { standings: vec![("Alice".to_string(), 0), ("Bob".to_string(), 0)], winner: None },
+        ]);
+        // Puis la connexion est fermée (le départ d'un joueur déjà déconnecté a pu être annoncé entre-temps).
+        while let Some(message) = client.next_event(TIMEOUT) {
+            assert!(matches!(message, ServerMessage::PlayerList { .. }), "unexpected message {:?}", message);
+        }
+    }
+    server_stopped.recv_timeout(TIMEOUT).expect("the server did not stop");
+    assert_eq!(std::fs::read_to_string(&leaderboard).unwrap(), "Alice\t0\nBob\t0\n");
+    std::fs::remove_file(&leaderboard).ok();
+}
@@ -0,0 +1,206 @@
+// Tests de propriétés de l'encodage des messages : tout message encodé est décodé à l'identique,
+// et des octets quelconques reçus du réseau ne font jamais paniquer le décodage.
+use number_game::codec::{decode, read_frame, read_message, write_message, MAX_FRAME_LEN};
+use number_game::protocol::{AdminCommand, AdminError, AuthError, ChatError, ClientMessage, Difficulty, GamePhase, GuessError, Hint, Language, ServerMessage, Temperature};
+use proptest::prelude::*;
+
+fn difficulty() -> impl Strategy<Value = Difficulty> {
+    prop_oneof![Just(Difficulty::Easy), Just(Difficulty::Medium), Just(Difficulty::Hard)]
+}
+
+fn language() -> impl Strategy<Value = Language> {
+    prop_oneof![Just(Language::French), Just(Language::English)]
+}
+
+fn game_phase() -> impl Strategy<Value = GamePhase> {
+    prop_oneof![Just(GamePhase::Identification), Just(GamePhase::Voting), Just(GamePhase::Playing)]
+}
+
+fn guess_error() -> impl Strategy<Value = GuessError> {
+    prop_oneof![Just(GuessError::WrongPhase), Just(GuessError::NotYourTurn), Just(GuessError::Spectating), Just(GuessError::NextRound)]
+}
+
+fn chat_error() -> impl Strategy<Value = ChatError> {
+    prop_oneof![
+        Just(ChatError::Empty),
+        any::<u32>().prop_map(ChatError::TooLong),
+        Just(ChatError::RateLimited),
+        Just(ChatError::Blocked),
+        Just(ChatError::UnknownRecipient),
+    ]
+}
+
+fn admin_command() -> impl Strategy<Value = AdminCommand> {
+    prop_oneof![
+        text().prop_map(AdminCommand::Kick),
+        text().prop_map(AdminCommand::Ban),
+        Just(AdminCommand::ForceStart),
+        Just(AdminCommand::SkipVoting),
+        Just(AdminCommand::EndRound),
+        difficulty().prop_map(AdminCommand::SetDifficulty),
+        text().prop_map(AdminCommand::TransferHost),
+    ]
+}
+
+fn admin_error() -> impl Strategy<Value = AdminError> {
+    prop_oneof![
+        Just(AdminError::NotHost),
+        Just(AdminError::WrongPhase),
+        Just(AdminError::UnknownPlayer),
+        Just(AdminError::OwnPlayer),
+        Just(AdminError::NoPlayers),
+    ]
+}
+
+fn auth_error() -> impl Strategy<Value = AuthError> {
+    prop_oneof![
+        Just(AuthError::WrongCredentials),
+        Just(AuthError::NameTaken),
+        Just(AuthError::NameReserved),
+        Just(AuthError::GuestsNotAllowed),
+        Just(AuthError::InvalidName),
+        Just(AuthError::WeakPassword),
+        Just(AuthError::Unavailable),
+        Just(AuthError::NameInUse),
+        Just(AuthError::TooManyAttempts),
+    ]
+}
+
+fn temperature() -> impl Strategy<Value = Temperature> {
+    prop_oneof![
+        Just(Temperature::Burning),
+        Just(Temperature::Hot),
+        Just(Temperature::Warm),
+        Just(Temperature::Cold),
+        Just(Temperature::Freezing),
+    ]
+}
+
+fn hint() -> impl Strategy<Value = Hint> {
+    prop_oneof![
+        Just(Hint::Higher),
+        Just(Hint::Lower),
+        Just(Hint::Correct),
+        temperature().prop_map(Hint::Temperature),
+        any::<bool>().prop_map(|even| Hint::Parity { even }),
+        (any::<u32>(), any::<bool>()).prop_map(|(divisor, divisible)| Hint::Divisible { divisor, divisible }),
+        any::<u32>().prop_map(Hint::DigitSum),
+        (any::<u32>(), any::<bool>()).prop_map(|(distance, within)| Hint::Within { distance, within }),
+    ]
+}
+
+// Noms et textes : n'importe quels caractères, y compris '\n'.
+fn text() -> impl Strategy<Value = String> {
+    ".{0,40}"
+}
+
+fn hints() -> impl Strategy<Value = Vec<Hint>> {
+    prop::collection::vec(hint(), 0..6)
+}
+
+fn scores() -> impl Strategy<Value = Vec<(String, u32)>> {
+    prop::collection::vec((text(), any::<u32>()), 0..8)
+}
+
+fn server_message() -> impl Strategy<Value = ServerMessage> {
+    prop_oneof![
+        any::<u32>().prop_map(ServerMessage::TimerStart),
+        difficulty().prop_map(ServerMessage::GameStart),
+        hints().prop_map(ServerMessage::Hint),
+        text().prop_map(ServerMessage::PlayerWon),
+        Just(ServerMessage::GameEnd),
+        (any::<u32>(), text()).prop_map(|(guess, player)| ServerMessage::Guess(guess, player)),
+        (any::<u32>(), text(), hints()).prop_map(|(guess, player, hints)| ServerMessage::GuessWithHint(guess, player, hints)),
+        difficulty().prop_map(ServerMessage::DifficultyVote),
+        (prop::collection::vec(text(), 0..8), prop::collection::vec(text(), 0..8), prop::collection::vec(text(), 0..8))
+            .prop_map(|(players, spectators, waiting)| ServerMessage::PlayerList { players, spectators, waiting }),
+        any::<u32>().prop_map(ServerMessage::VotingCountdown),
+        any::<u32>().prop_map(ServerMessage::TimeRemaining),
+        any::<u32>().prop_map(|secret| ServerMessage::RoundTimeout { secret }),
+        (any::<u32>(), any::<u32>(), scores()).prop_map(|(round, rounds, standings)| ServerMessage::Standings { round, rounds, standings }),
+        (scores(), prop::option::of(text())).prop_map(|(standings, winner)| ServerMessage::MatchEnd { standings, winner }),
+        text().prop_map(ServerMessage::Turn),
+        text().prop_map(ServerMessage::TurnSkipped),
+        guess_error().prop_map(ServerMessage::GuessRejected),
+        (text(), any::<u32>()).prop_map(|(player, attempts)| ServerMessage::RaceProgress(player, attempts)),
+        scores().prop_map(|secrets| ServerMessage::RaceTimeout { secrets }),
+        text().prop_map(ServerMessage::Notice),
+        scores().prop_map(ServerMessage::Leaderboard),
+        (text(), text(), any::<u64>()).prop_map(|(from, text, timestamp)| ServerMessage::ChatMessage { from, text, timestamp }),
+        (text(), text(), text(), any::<u64>()).prop_map(|(from, to, text, timestamp)| ServerMessage::Whisper { from, to, text, timestamp }),
+        chat_error().prop_map(ServerMessage::ChatRejected),
+        any::<u32>().prop_map(ServerMessage::SecretRevealed),
+        (prop::option::of(text()), prop::collection::vec(text(), 0..8), any::<u32>(), prop::option::of(any::<u32>()))
+            .prop_map(|(host, ready, min_players, countdown)| ServerMessage::Lobby { host, ready, min_players, countdown }),
+        (game_phase(), prop::option::of(difficulty()), any::<u32>(), any::<u32>(), prop::option::of(any::<u32>()))
+            .prop_map(|(phase, difficulty, round, rounds, remaining)| ServerMessage::GameState { phase, difficulty, round, rounds, remaining }),
+        (prop::option::of(text()), admin_command()).prop_map(|(by, action)| ServerMessage::AdminAction { by, action }),
+        admin_error().prop_map(ServerMessage::AdminRejected),
+        text().prop_map(ServerMessage::Announcement),
+        (prop::option::of(text()), any::<u32>()).prop_map(|(reason, seconds)| ServerMessage::ServerShutdown { reason, seconds }),
+        text().prop_map(ServerMessage::Authenticated),
+        auth_error().prop_map(ServerMessage::AuthRejected),
+    ]
+}
+
+fn client_message() -> impl Strategy<Value = ClientMessage> {
+    prop_oneof![
+        any::<u32>().prop_map(ClientMessage::Guess),
+        difficulty().prop_map(ClientMessage::DifficultyVote),
+        (text(), language()).prop_map(|(name, language)| ClientMessage::Join { name, language }),
+        Just(ClientMessage::RequestPlayers),
+        Just(ClientMessage::RequestLeaderboard),
+        text().prop_map(ClientMessage::Chat),
+        (text(), text()).prop_map(|(to, text)| ClientMessage::Whisper { to, text }),
+        (text(), language()).prop_map(|(name, language)| ClientMessage::JoinAsSpectator { name, language }),
+        Just(ClientMessage::Ready),
+        Just(ClientMessage::StartMatch),
+        admin_command().prop_map(ClientMessage::Admin),
+        (text(), text()).prop_map(|(name, password)| ClientMessage::Login { name, password }),
+        (text(), text()).prop_map(|(name, password)| ClientMessage::Register { name, password }),
+    ]
+}
+
+proptest! {
+    // Tout message du serveur encodé en trame est relu à l'identique.
+    #[test]
+    fn server_message_round_trip(message in server_message()) {
+        let mut frame = vec![];
+        write_message(&mut frame, &message).unwrap();
+        prop_assert!(frame.len() <= MAX_FRAME_LEN as usize + 4);
+        prop_assert_eq!(read_message::<ServerMessage>(&mut frame.as_slice()).unwrap(), Some(message));
+    }
+
+    // Tout message du client encodé en trame est relu à l'identique.
+    #[test]
+    fn client_message_round_trip(message in client_message()) {
+        let mut frame = vec![];
+        write_message(&mut frame, &message).unwrap();
+        prop_assert_eq!(read_message::<ClientMessage>(&mut frame.as_slice()).unwrap(), Some(message));
+    }
+
+    // Plusieurs trames à la suite sont relues dans l'ordre, sans se mélanger.
+    #[test]
+    fn consecutive_frames(messages in prop::collection::vec(client_message(), 0..10)) {
+        let mut stream = vec![];
+        for message in &messages {
+            write_message(&mut stream, message).unwrap();
+        }
+        let mut reader = stream.as_slice();
+        for message in messages {
+            prop_assert_eq!(read_message::<ClientMessage>(&mut reader).unwrap(), Some(message));
+        }
+        prop_assert_eq!(read_message::<ClientMessage>(&mut reader).unwrap(), None);
+    }
+
+    // Des octets quelconques ne font jamais paniquer le décodage ni la lecture des trames.
+    #[test]
+    fn arbitrary_bytes_never_panic(bytes in prop::collection::vec(any::<u8>(), 0..256)) {
+        let _ = decode::<ClientMessage>(&bytes);
+        let _ = decode::<ServerMessage>(&bytes);
+        let mut reader = bytes.as_slice();
+        while let Ok(Some(payload)) = read_frame(&mut reader) {
+            let _ = decode::<ClientMessage>(&payload);
+        }
+    }
+}